@@ -2,6 +2,12 @@
 
 #[ink::contract]
 mod hotpotato {
+    use ink::{
+        env::hash::{HashOutput, Keccak256},
+        prelude::vec::Vec,
+        storage::{Lazy, Mapping},
+        H160, H256, U256,
+    };
 
     /// Hot Potato Game Contract Errors
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -15,308 +21,6954 @@ mod hotpotato {
         DeadlinePassed,
         /// Only current holder can pass the potato
         NotCurrentHolder,
+        /// Only the game starter can perform this action
+        OnlyStarter,
+        /// The recipient is not a valid account
+        InvalidRecipient,
+        /// Nobody is holding the potato
+        NoHolder,
+        /// No game exists with the given id
+        GameNotFound,
+        /// The game is no longer accepting players
+        NotInLobby,
+        /// The caller already joined this game
+        AlreadyRegistered,
+        /// The caller has not joined this game
+        NotRegistered,
+        /// The lobby already holds `max_players`
+        LobbyFull,
+        /// Fewer than `min_players` have joined
+        NotEnoughPlayers,
+        /// Player limits are out of range or inconsistent
+        InvalidPlayerLimits,
+        /// The recipient has not joined this game
+        RecipientNotRegistered,
+        /// The recipient was already eliminated
+        RecipientEliminated,
+        /// The transferred value does not match the game's entry fee
+        IncorrectEntryFee,
+        /// Transferring funds to the caller failed
+        PayoutFailed,
+        /// The caller has no claimable balance
+        NothingToWithdraw,
+        /// The keeper reward exceeds the contract's maximum
+        InvalidKeeperReward,
+        /// The deadline window, floor or curve is out of range
+        InvalidDeadline,
+        /// The hidden fuse bounds are inconsistent or used outside `Classic`
+        InvalidFuse,
+        /// The game does not use a hidden fuse
+        NoHiddenFuse,
+        /// A hidden fuse game must be started with a fuse commitment
+        FuseCommitmentRequired,
+        /// The transferred value does not match the game's fuse bond
+        IncorrectFuseBond,
+        /// The secret does not match the fuse commitment
+        InvalidFuseReveal,
+        /// The revealed fuse has not burned down yet
+        FuseNotExpired,
+        /// The reveal window has closed
+        RevealWindowClosed,
+        /// The game is not collecting entropy reveals
+        NotSeeding,
+        /// The secret does not match the caller's entropy commitment
+        InvalidEntropyReveal,
+        /// The caller already revealed their entropy
+        AlreadyRevealed,
+        /// Players are still revealing and the reveal window is open
+        EntropyPending,
+        /// Only the contract owner can perform this action
+        OnlyOwner,
+        /// The caller has not been proposed as the next owner
+        NotPendingOwner,
+        /// The minimum entry fee exceeds the maximum
+        InvalidFeeLimits,
+        /// The entry fee is outside the contract's bounds
+        EntryFeeOutOfRange,
+        /// The game mode has been disabled by the owner
+        ModeDisabled,
+        /// At least one game mode must stay enabled
+        NoModeEnabled,
+        /// Only the owner or the guardian can pause or unpause
+        OnlyGuardian,
+        /// Play is paused
+        ContractPaused,
+        /// Play is not paused
+        NotPaused,
+        /// Replacing the contract code failed
+        UpgradeFailed,
+        /// Storage is already at `STORAGE_VERSION`
+        AlreadyMigrated,
+        /// The leaderboard size is zero or exceeds `MAX_LEADERBOARD_SIZE`
+        InvalidLeaderboardSize,
+        /// The game has not ended yet
+        GameNotEnded,
+        /// The holder tried to pass the potato to themselves
+        SelfPass,
+        /// The recipient passed the potato too recently to get it back
+        PassBackTooSoon,
+        /// The recipient already received the potato as often as allowed
+        ReceiveLimitReached,
+        /// The pass-back window exceeds `MAX_PASS_BACK_WINDOW`
+        InvalidPassRules,
+        /// The holder has not kept the potato for the minimum hold yet
+        HoldTooShort,
+        /// The game ran past the game-length cap and must be resolved
+        GameLengthCapped,
+        /// The game-length cap or the default deadline does not leave room
+        /// for a single hold
+        InvalidTimeLimits,
+        /// Team games need a valid `TeamConfig` and other games must not
+        /// use `GameMode::Teams`
+        InvalidTeamConfig,
+        /// The team does not exist in this game
+        InvalidTeam,
+        /// Team games are joined through `join_team`
+        TeamRequired,
+        /// The game is not a team game
+        NotTeamGame,
+        /// The recipient plays for the holder's own team
+        SameTeam,
+        /// Fewer than two teams have players
+        NotEnoughTeams,
+        /// The potato count is zero, above `MAX_POTATOES`, or above one in a
+        /// hidden fuse game
+        InvalidPotatoCount,
+        /// The game has no potato with this index
+        InvalidPotato,
+        /// The recipient already holds another potato
+        AlreadyHoldsPotato,
+        /// A session key cannot go to the zero address, the player or
+        /// another player of the game
+        InvalidDelegate,
+        /// The session key would expire before the next block
+        InvalidExpiry,
+        /// The delegate holds a live session key of another player for this
+        /// game
+        SessionKeyTaken,
+        /// The caller authorized no session key for this delegate
+        NoSessionKey,
+        /// The caller holds a live session key for this game and cannot join
+        /// it as a player
+        DelegateCannotJoin,
     }
 
     /// Hot Potato Game Result Type
     pub type Result<T> = core::result::Result<T, HotPotatoError>;
 
-    /// 🎯 EVENTS - Will add back after basic contract works
-    /// For now, frontend can query contract state directly
+    /// Identifier of a game inside the contract
+    pub type GameId = u64;
 
-    /// 📦 Contract Storage
-    #[ink(storage)]
-    pub struct Hotpotato {
+    /// Upper bound on how many ids `get_active_games` returns per page
+    pub const MAX_PAGE_SIZE: u32 = 50;
+
+    /// Smallest lobby a game can be created with
+    pub const MIN_PLAYERS: u32 = 2;
+
+    /// Largest lobby a game can be created with
+    pub const MAX_PLAYERS: u32 = 64;
+
+    /// Basis points in 100%
+    pub const BPS_DENOMINATOR: u16 = 10_000;
+
+    /// Largest share of the pool a single burn may ever pay to its keeper
+    pub const MAX_KEEPER_REWARD_BPS: u16 = 1_000;
+
+    /// Version of the storage layout this code reads and writes
+    pub const STORAGE_VERSION: u32 = 1;
+
+    /// Only account allowed to migrate the first version's storage, which had
+    /// no owner, and the owner it gets; set it to the operator's address
+    /// before building the upgrade
+    pub const LEGACY_OWNER: H160 = H160([0x0A; 20]);
+
+    /// Entries each leaderboard keeps until the owner picks another size
+    pub const DEFAULT_LEADERBOARD_SIZE: u32 = 10;
+
+    /// Largest leaderboard the owner can ask for
+    pub const MAX_LEADERBOARD_SIZE: u32 = 100;
+
+    /// Score for every game a player survives
+    pub const SURVIVAL_POINTS: u64 = 1;
+
+    /// Extra score for winning a game as the last one standing
+    pub const WIN_POINTS: u64 = 3;
+
+    /// Rating of a player who has not finished a game yet
+    pub const INITIAL_RATING: u32 = 1_500;
+
+    /// Most a rating can move in a single game
+    pub const RATING_K: u32 = 32;
+
+    /// Rating nobody drops below
+    pub const MIN_RATING: u32 = 100;
+
+    /// Most recent passes the pass-back rule can look at
+    pub const MAX_PASS_BACK_WINDOW: u32 = 16;
+
+    /// Most teams a team game can have
+    pub const MAX_TEAMS: u32 = 8;
+
+    /// Most potatoes a game can be played with at once
+    pub const MAX_POTATOES: u32 = 16;
+
+    /// Lifecycle of a game
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum GameStatus {
+        /// Players can join and leave
+        Lobby,
+        /// Players reveal the entropy they committed to when joining
+        Seeding,
+        /// The potato is being passed
+        Active,
+        /// The game is over
+        Ended,
+    }
+
+    /// How a burn affects the game
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum GameMode {
+        /// The first burn ends the game
+        Classic,
+        /// Each burn eliminates the holder and starts a new round until a
+        /// single survivor remains
+        Elimination,
+        /// Players play in teams; each burn counts against the holder's
+        /// team and starts a new round, see `TeamConfig`
+        Teams,
+    }
+
+    /// Clock a deadline is measured on
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum DeadlineUnit {
+        /// Block numbers
+        Blocks,
+        /// Milliseconds of block timestamp
+        Milliseconds,
+    }
+
+    /// How the window shrinks as the potato gets passed
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum DeadlineCurve {
+        /// Every holder gets the full window
+        Constant,
+        /// The window drops by `step` with each pass
+        Linear { step: u64 },
+        /// The window keeps `ratio_bps` of its previous length with each pass
+        Geometric { ratio_bps: u16 },
+    }
+
+    /// 🕰️ A reading of both clocks a deadline can be measured on
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ClockReading {
+        /// Block number
+        pub blocks: u64,
+        /// Block timestamp in milliseconds
+        pub millis: u64,
+    }
+
+    impl ClockReading {
+        /// The reading on the clock `unit` is measured on
+        fn get(&self, unit: DeadlineUnit) -> u64 {
+            match unit {
+                DeadlineUnit::Blocks => self.blocks,
+                DeadlineUnit::Milliseconds => self.millis,
+            }
+        }
+    }
+
+    /// ⏱️ How long a holder may keep the potato
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Deadline {
+        /// Time the first holder of a round has to pass the potato, in `unit`
+        pub window: u64,
+        /// Clock `window` is measured on
+        pub unit: DeadlineUnit,
+        /// How the window shrinks with each pass
+        pub curve: DeadlineCurve,
+        /// Shortest window the curve can shrink to
+        pub floor: u64,
+    }
+
+    impl Deadline {
+        /// A window of the same length for every holder
+        pub const fn constant(window: u64, unit: DeadlineUnit) -> Self {
+            Self {
+                window,
+                unit,
+                curve: DeadlineCurve::Constant,
+                floor: 0,
+            }
+        }
+
+        /// Check the curve can actually be followed
+        fn validate(&self) -> Result<()> {
+            if self.window == 0 || self.floor > self.window {
+                return Err(HotPotatoError::InvalidDeadline);
+            }
+            if let DeadlineCurve::Geometric { ratio_bps } = self.curve {
+                if ratio_bps > BPS_DENOMINATOR {
+                    return Err(HotPotatoError::InvalidDeadline);
+                }
+            }
+            Ok(())
+        }
+
+        /// Window of the holder who receives the potato after `passes`
+        /// passes, never below `floor`
+        fn window_after(&self, passes: u32) -> u64 {
+            let window = match self.curve {
+                DeadlineCurve::Constant => self.window,
+                DeadlineCurve::Linear { step } => self
+                    .window
+                    .saturating_sub(step.saturating_mul(u64::from(passes))),
+                DeadlineCurve::Geometric { ratio_bps } => {
+                    let mut window = self.window;
+                    for _ in 0..passes {
+                        // Stop early once the floor is reached or the window
+                        // stops shrinking
+                        if window <= self.floor {
+                            break;
+                        }
+                        let next = (u128::from(window) * u128::from(ratio_bps)
+                            / u128::from(BPS_DENOMINATOR))
+                            as u64;
+                        if next == window {
+                            break;
+                        }
+                        window = next;
+                    }
+                    window
+                }
+            };
+            window.max(self.floor)
+        }
+    }
+
+    /// 🧨 Bounds for a hidden fuse committed by the starter
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct FuseConfig {
+        /// Shortest fuse the starter may commit to, in the deadline's unit
+        pub min_fuse: u64,
+        /// Longest fuse the starter may commit to, in the deadline's unit
+        pub max_fuse: u64,
+        /// Time the starter has to reveal once `max_fuse` has passed
+        pub reveal_window: u64,
+        /// Value the starter stakes on revealing; it joins the prize pool if
+        /// the fuse is never revealed
+        pub bond: Balance,
+    }
+
+    impl FuseConfig {
+        /// Fuse length drawn from the starter's `secret` and the game seed
+        fn length_for(&self, secret: &Hash, seed: &Hash) -> u64 {
+            let span = (self.max_fuse - self.min_fuse).saturating_add(1);
+            self.min_fuse + hash_word(&keccak_encoded(&(secret, seed))) % span
+        }
+    }
+
+    /// ⚙️ Options chosen when a game is created
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GameConfig {
+        /// Players required before the game can start
+        pub min_players: u32,
+        /// Players the lobby accepts at most
+        pub max_players: u32,
+        /// What happens when the potato burns
+        pub mode: GameMode,
+        /// Value every player pays into the prize pool when joining
+        pub entry_fee: Balance,
+        /// Share of the pool, in basis points, credited to whoever calls
+        /// `check_deadline` and burns the potato
+        pub keeper_reward_bps: u16,
+        /// Deadline for this game; the contract default when `None`
+        pub deadline: Option<Deadline>,
+        /// Burn at a secret fuse length instead of a known holder deadline
+        pub fuse: Option<FuseConfig>,
+        /// Potatoes in play at once, each with its own holder and deadline
+        pub potatoes: u32,
+    }
+
+    impl Default for GameConfig {
+        fn default() -> Self {
+            Self {
+                min_players: MIN_PLAYERS,
+                max_players: MAX_PLAYERS,
+                mode: GameMode::Classic,
+                entry_fee: 0,
+                keeper_reward_bps: 0,
+                deadline: None,
+                fuse: None,
+                potatoes: 1,
+            }
+        }
+    }
+
+    impl GameConfig {
+        /// Check the options against the contract-wide bounds
+        fn validate(&self, settings: &Settings) -> Result<()> {
+            if self.min_players < settings.min_players
+                || self.max_players > settings.max_players
+                || self.min_players > self.max_players
+            {
+                return Err(HotPotatoError::InvalidPlayerLimits);
+            }
+            if self.keeper_reward_bps > settings.max_keeper_reward_bps {
+                return Err(HotPotatoError::InvalidKeeperReward);
+            }
+            if self.entry_fee < settings.min_entry_fee || self.entry_fee > settings.max_entry_fee {
+                return Err(HotPotatoError::EntryFeeOutOfRange);
+            }
+            if !settings.is_enabled(self.mode) {
+                return Err(HotPotatoError::ModeDisabled);
+            }
+            if let Some(deadline) = &self.deadline {
+                deadline.validate()?;
+            }
+            if let Some(fuse) = &self.fuse {
+                // A fuse burns once, so it only fits games that end on the
+                // first burn
+                if fuse.min_fuse > fuse.max_fuse || self.mode != GameMode::Classic {
+                    return Err(HotPotatoError::InvalidFuse);
+                }
+            }
+            // Nobody holds two potatoes, and a fuse burns a single holder
+            if self.potatoes == 0
+                || self.potatoes > MAX_POTATOES
+                || self.potatoes >= self.max_players
+                || (self.potatoes > 1 && self.fuse.is_some())
+            {
+                return Err(HotPotatoError::InvalidPotatoCount);
+            }
+            Ok(())
+        }
+    }
+
+    /// 🛠️ Contract-wide bounds on new games, tuned by the owner
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Settings {
+        /// Smallest `min_players` a game may ask for
+        pub min_players: u32,
+        /// Largest `max_players` a game may ask for
+        pub max_players: u32,
+        /// Smallest entry fee a game may charge
+        pub min_entry_fee: Balance,
+        /// Largest entry fee a game may charge
+        pub max_entry_fee: Balance,
+        /// Largest keeper reward a game may offer, in basis points
+        pub max_keeper_reward_bps: u16,
+        /// Whether new `Classic` games can be created
+        pub classic_enabled: bool,
+        /// Whether new `Elimination` games can be created
+        pub elimination_enabled: bool,
+        /// Whether new `Teams` games can be created
+        pub teams_enabled: bool,
+    }
+
+    impl Default for Settings {
+        fn default() -> Self {
+            Self {
+                min_players: MIN_PLAYERS,
+                max_players: MAX_PLAYERS,
+                min_entry_fee: 0,
+                max_entry_fee: Balance::MAX,
+                max_keeper_reward_bps: MAX_KEEPER_REWARD_BPS,
+                classic_enabled: true,
+                elimination_enabled: true,
+                teams_enabled: true,
+            }
+        }
+    }
+
+    impl Settings {
+        /// Check the bounds against the hard limits of the contract
+        fn validate(&self) -> Result<()> {
+            if self.min_players < MIN_PLAYERS
+                || self.max_players > MAX_PLAYERS
+                || self.min_players > self.max_players
+            {
+                return Err(HotPotatoError::InvalidPlayerLimits);
+            }
+            if self.min_entry_fee > self.max_entry_fee {
+                return Err(HotPotatoError::InvalidFeeLimits);
+            }
+            if self.max_keeper_reward_bps > MAX_KEEPER_REWARD_BPS {
+                return Err(HotPotatoError::InvalidKeeperReward);
+            }
+            if !self.classic_enabled && !self.elimination_enabled && !self.teams_enabled {
+                return Err(HotPotatoError::NoModeEnabled);
+            }
+            Ok(())
+        }
+
+        /// Whether new games may use `mode`
+        fn is_enabled(&self, mode: GameMode) -> bool {
+            match mode {
+                GameMode::Classic => self.classic_enabled,
+                GameMode::Elimination => self.elimination_enabled,
+                GameMode::Teams => self.teams_enabled,
+            }
+        }
+    }
+
+    /// What an owner setter changed
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ConfigChange {
+        /// The deadline applied to new games
+        DefaultDeadline(Deadline),
+        /// The player limits new games must stay within
+        PlayerLimits { min: u32, max: u32 },
+        /// The entry fee bounds new games must stay within
+        EntryFeeLimits { min: Balance, max: Balance },
+        /// The largest keeper reward new games may offer
+        MaxKeeperReward(u16),
+        /// Whether new games may use a mode
+        ModeEnabled { mode: GameMode, enabled: bool },
+        /// Entries each leaderboard keeps
+        LeaderboardSize(u32),
+        /// Rules every pass must follow
+        PassRules(PassRules),
+        /// Minimum hold, game-length cap and tie-break
+        TimeLimits(TimeLimits),
+        /// The account that may pause play besides the owner
+        Guardian(Option<H160>),
+    }
+
+    /// 🎲 Randomness beacon fed by the players of a game
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Beacon {
+        /// Latest moment players may reveal, in the deadline's unit
+        pub reveal_by: u64,
+        /// Players who revealed so far
+        pub reveals: u32,
+        /// XOR of every revealed secret
+        pub mix: Hash,
+        /// Seed the game was started with
+        pub seed: Option<Hash>,
+    }
+
+    /// Entropy a player committed to when joining
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Entropy {
+        /// See `entropy_commitment`
+        pub commitment: Hash,
+        /// Has the player revealed the secret yet?
+        pub revealed: bool,
+    }
+
+    /// Hidden fuse of a running game
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct HiddenFuse {
+        /// Hash of the starter's secret, see `fuse_commitment`
+        pub commitment: Hash,
+        /// When the fuse was lit, in the deadline's unit
+        pub lit_at: u64,
+        /// Latest moment the fuse can burn down
+        pub expires_by: u64,
+        /// Latest moment the starter may reveal
+        pub reveal_by: u64,
+        /// Bond staked by the starter, returned on a valid reveal
+        pub bond: Balance,
+        /// Entries in the game's holder log
+        pub holder_changes: u32,
+        /// Fuse length, once revealed
+        pub revealed: Option<u64>,
+    }
+
+    /// Entry in a hidden fuse game's holder log
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct HolderChange {
+        /// When the potato changed hands, in the deadline's unit
+        pub at: u64,
+        /// Who received it
+        pub holder: H160,
+    }
+
+    /// 🚦 Rules every pass must follow, on top of the recipient being a
+    /// player still in the game
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PassRules {
+        /// Whether the holder may pass to themselves, restarting their own
+        /// deadline
+        pub allow_self_pass: bool,
+        /// Players who passed within the game's last this many passes cannot
+        /// get a potato back; 0 turns the rule off. The window spans the
+        /// passes of every potato in the game.
+        pub pass_back_window: u32,
+        /// Most times a player may receive a potato per game, counting every
+        /// potato; 0 means no limit
+        pub max_receives: u32,
+    }
+
+    impl PassRules {
+        /// Check the rules against the hard limits of the contract
+        fn validate(&self) -> Result<()> {
+            if self.pass_back_window > MAX_PASS_BACK_WINDOW {
+                return Err(HotPotatoError::InvalidPassRules);
+            }
+            Ok(())
+        }
+    }
+
+    /// ⚖️ Who loses a game that hits the game-length cap
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum TieBreak {
+        /// Every current holder burns, as if their deadline had expired
+        #[default]
+        Holder,
+        /// The player who received the potato most often burns; ties go to
+        /// the current holder, then to the earliest seat
+        MostReceived,
+        /// Nobody burns and every player still in shares the pool
+        Split,
+    }
+
+    /// ⏳ Limits that keep a game from stalling forever, in blocks on the
+    /// game clock
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct TimeLimits {
+        /// Blocks the holder must keep the potato before passing it; 0
+        /// turns the rule off
+        pub min_hold: u64,
+        /// Blocks a game may run before `check_deadline` ends it anyway; 0
+        /// means no cap
+        pub max_game_length: u64,
+        /// Who loses when the cap is reached
+        pub tie_break: TieBreak,
+    }
+
+    impl TimeLimits {
+        /// Check that a holder can still pass at least once before the cap
+        /// and before the default `deadline` burns them
+        fn validate(&self, deadline: &Deadline) -> Result<()> {
+            if self.max_game_length > 0 && self.max_game_length <= self.min_hold {
+                return Err(HotPotatoError::InvalidTimeLimits);
+            }
+            if !self.fits(deadline) {
+                return Err(HotPotatoError::InvalidTimeLimits);
+            }
+            Ok(())
+        }
+
+        /// Whether the minimum hold ends before `deadline`'s window does;
+        /// blocks cannot be compared with milliseconds, so windows on the
+        /// timestamp clock always fit
+        fn fits(&self, deadline: &Deadline) -> bool {
+            self.min_hold == 0
+                || deadline.unit != DeadlineUnit::Blocks
+                || self.min_hold < deadline.window
+        }
+    }
+
+    /// 🛡️ How a team game is played
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct TeamConfig {
+        /// Teams players can join, numbered from 0
+        pub teams: u32,
+        /// Rounds the game lasts; every round ends in a burn
+        pub rounds: u32,
+        /// Burns that end the game early once a single team has taken them;
+        /// 0 means only the round count ends the game
+        pub max_burns: u32,
+    }
+
+    impl TeamConfig {
+        /// Check the options against the hard limits of the contract
+        fn validate(&self) -> Result<()> {
+            if self.teams < 2 || self.teams > MAX_TEAMS || self.rounds == 0 {
+                return Err(HotPotatoError::InvalidTeamConfig);
+            }
+            Ok(())
+        }
+    }
+
+    /// A team of a team game and the burns counted against it
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct TeamStanding {
+        /// Team number
+        pub team: u32,
+        /// Players on the team, in join order
+        pub members: Vec<H160>,
+        /// Burns the team has taken
+        pub burns: u32,
+    }
+
+    /// 📜 A single pass, kept so disputes can be settled on-chain
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PassRecord {
+        /// Index of the potato that was passed
+        pub potato: u32,
+        /// Holder who passed
+        pub from: H160,
+        /// Player who received the potato
+        pub to: H160,
+        /// Block the pass was made in
+        pub block: u32,
+        /// When the pass was made, on the game clock in the deadline's unit
+        pub at: u64,
+        /// Time that was left on the passer's deadline
+        pub remaining: u64,
+    }
+
+    /// Which pass records of a game are still stored
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PassLog {
+        /// Index of the oldest record not pruned yet
+        pub pruned: u32,
+        /// Number of passes ever recorded
+        pub len: u32,
+    }
+
+    /// 📈 Lifetime record of a player across every game
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PlayerStats {
+        /// Games the player was seated in when they started
+        pub games_played: u32,
+        /// Passes the player made
+        pub passes: u32,
+        /// Times the potato burned in the player's hands
+        pub burns: u32,
+        /// Games the player won as the last one standing
+        pub wins: u32,
+        /// Blocks the player spent holding the potato
+        pub blocks_held: u64,
+        /// Fewest blocks the player held the potato before passing it
+        pub fastest_pass: Option<u64>,
+    }
+
+    /// Running totals the leaderboards rank that `PlayerStats` does not
+    /// keep
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Standing {
+        /// Games survived in a row, reset by a burn
+        pub streak: u32,
+        /// `SURVIVAL_POINTS` per game survived plus `WIN_POINTS` per win
+        pub score: u64,
+    }
+
+    /// 🏅 What a leaderboard ranks players by
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum LeaderboardMetric {
+        /// Games won
+        Wins,
+        /// Games survived in a row
+        Streak,
+        /// Points for survivals and wins
+        Score,
+    }
+
+    /// A ranked player and their value on the leaderboard's metric
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct LeaderboardEntry {
+        /// Ranked player
+        pub player: H160,
+        /// Value on the metric
+        pub value: u64,
+    }
+
+    /// 🥔 A potato in play, stored per game and index
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Potato {
+        /// Current holder
+        pub holder: Option<H160>,
+        /// When the potato was last passed, in the deadline's unit
+        pub last_passed_at: u64,
+        /// Passes made with it in the current round, which shrink the window
+        pub pass_count: u32,
+    }
+
+    /// 🔑 A delegate allowed to pass the potato on a player's behalf in one
+    /// game
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct SessionKey {
+        /// Player the delegate acts for
+        pub principal: H160,
+        /// Last block the key can be used in
+        pub expires_at: u32,
+    }
+
+    /// 🥔 State of a single game
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Game {
+        /// Who created the game and may launch it
+        pub starter: H160,
+        /// Deadline, fixed when the game is created
+        pub deadline: Deadline,
+        /// Where the game is in its lifecycle
+        pub status: GameStatus,
+        /// Players still in the game, in join order
+        pub players: Vec<H160>,
+        /// Players knocked out, in the order they were eliminated
+        pub eliminated: Vec<H160>,
+        /// Options the game was created with
+        pub config: GameConfig,
+        /// Current round, starting at 1 once the game starts
+        pub round: u32,
+        /// Block the current round started in
+        pub round_started_block: u32,
+        /// Last player standing, once the game has ended with one survivor
+        pub winner: Option<H160>,
+        /// Entry fees collected and not yet paid out
+        pub pool: Balance,
+        /// Committed fuse, for hidden fuse games past the lobby
+        pub fuse: Option<HiddenFuse>,
+        /// Entropy collected to pick the first holder and the fuse
+        pub beacon: Beacon,
+    }
+
+    impl Game {
+        /// Is the potato currently being passed?
+        fn is_active(&self) -> bool {
+            self.status == GameStatus::Active
+        }
+
+        /// Is `player` still in the game?
+        fn is_player(&self, player: &H160) -> bool {
+            self.players.contains(player)
+        }
+
+        /// Check that `to` may receive the potato
+        fn ensure_can_receive(&self, to: &H160) -> Result<()> {
+            if self.eliminated.contains(to) {
+                return Err(HotPotatoError::RecipientEliminated);
+            }
+            if !self.is_player(to) {
+                return Err(HotPotatoError::RecipientNotRegistered);
+            }
+            Ok(())
+        }
+
+        /// Move `player` from the survivors to the elimination order and
+        /// return the seat they occupied
+        fn eliminate(&mut self, player: H160) -> usize {
+            let seat = self
+                .players
+                .iter()
+                .position(|p| *p == player)
+                .unwrap_or(self.players.len());
+            self.players.retain(|p| *p != player);
+            self.eliminated.push(player);
+            seat
+        }
+
+        /// Time the holder of `potato` was given to pass it
+        ///
+        /// A hidden fuse may burn at any moment up to `expires_by`, so that
+        /// is the only bound a holder can rely on.
+        fn window_of(&self, potato: &Potato) -> u64 {
+            match &self.fuse {
+                Some(fuse) => fuse.expires_by.saturating_sub(potato.last_passed_at),
+                None => self.deadline.window_after(potato.pass_count),
+            }
+        }
+
+        /// Moment after which the holder of `potato` gets burned
+        fn deadline_of(&self, potato: &Potato) -> u64 {
+            potato.last_passed_at.saturating_add(self.window_of(potato))
+        }
+
+        /// Time left on `potato` at `now`, in the deadline's unit
+        fn remaining_of(&self, potato: &Potato, now: u64) -> u64 {
+            if !self.is_active() {
+                return 0;
+            }
+            self.deadline_of(potato).saturating_sub(now)
+        }
+    }
+
+    // 🎯 EVENTS
+
+    /// Emitted when a new lobby opens
+    #[ink(event)]
+    pub struct GameCreated {
+        /// Game that was created
+        #[ink(topic)]
+        game_id: GameId,
+        /// Who created the game
+        #[ink(topic)]
+        starter: H160,
+        /// Players required to start
+        min_players: u32,
+        /// Players accepted at most
+        max_players: u32,
+        /// What happens when the potato burns
+        mode: GameMode,
+        /// Value every player pays to join
+        entry_fee: Balance,
+    }
+
+    /// Emitted when a player joins a lobby
+    #[ink(event)]
+    pub struct PlayerJoined {
+        /// Game that was joined
+        #[ink(topic)]
+        game_id: GameId,
+        /// Player who joined
+        #[ink(topic)]
+        player: H160,
+    }
+
+    /// Emitted when a player leaves a lobby
+    #[ink(event)]
+    pub struct PlayerLeft {
+        /// Game that was left
+        #[ink(topic)]
+        game_id: GameId,
+        /// Player who left
+        #[ink(topic)]
+        player: H160,
+    }
+
+    /// Emitted when the starter closes the lobby and players start revealing
+    #[ink(event)]
+    pub struct LobbyClosed {
+        /// Game that stopped accepting players
+        #[ink(topic)]
+        game_id: GameId,
+        /// Players who have to reveal
+        players: u32,
+        /// Latest moment to reveal, in the game's deadline unit
+        reveal_by: u64,
+    }
+
+    /// Emitted when a player reveals their entropy
+    #[ink(event)]
+    pub struct EntropyRevealed {
+        /// Game the entropy feeds
+        #[ink(topic)]
+        game_id: GameId,
+        /// Player who revealed
+        #[ink(topic)]
+        player: H160,
+    }
+
+    /// Emitted when a player who never revealed is dropped from the game;
+    /// their entry fee stays in the pool
+    #[ink(event)]
+    pub struct EntropyWithheld {
+        /// Game the player was dropped from
+        #[ink(topic)]
+        game_id: GameId,
+        /// Player who withheld their reveal
+        #[ink(topic)]
+        player: H160,
+    }
+
+    /// Emitted when a new game starts
+    #[ink(event)]
+    pub struct GameStarted {
+        /// Game that started
+        #[ink(topic)]
+        game_id: GameId,
+        /// Who created the game
+        #[ink(topic)]
+        starter: H160,
+        /// Initial holder of the first potato, drawn from the beacon
+        #[ink(topic)]
+        holder: H160,
+        /// Block the game started in
+        block: u32,
+        /// Time the first holder has to pass the first potato, in the
+        /// game's deadline unit
+        remaining: u64,
+    }
+
+    /// Emitted when the holder passes the potato
+    #[ink(event)]
+    pub struct PotatoPassed {
+        /// Game the pass happened in
+        #[ink(topic)]
+        game_id: GameId,
+        /// Index of the potato that was passed
+        potato: u32,
+        /// Previous holder
+        #[ink(topic)]
+        from: H160,
+        /// New holder
+        #[ink(topic)]
+        to: H160,
+        /// Block the potato was passed in
+        block: u32,
+        /// Time the previous holder had left when passing, in the game's
+        /// deadline unit
+        remaining: u64,
+        /// Time the new holder has to pass the potato on
+        window: u64,
+        /// Session key that made the pass for `from`, if any
+        delegate: Option<H160>,
+    }
+
+    /// Emitted when a player authorizes a session key
+    #[ink(event)]
+    pub struct SessionKeyAuthorized {
+        /// Game the key may pass in
+        #[ink(topic)]
+        game_id: GameId,
+        /// Player the key acts for
+        #[ink(topic)]
+        principal: H160,
+        /// Account holding the key
+        #[ink(topic)]
+        delegate: H160,
+        /// Last block the key can be used in
+        expires_at: u32,
+    }
+
+    /// Emitted when a player revokes a session key
+    #[ink(event)]
+    pub struct SessionKeyRevoked {
+        /// Game the key could pass in
+        #[ink(topic)]
+        game_id: GameId,
+        /// Player the key acted for
+        #[ink(topic)]
+        principal: H160,
+        /// Account that held the key
+        #[ink(topic)]
+        delegate: H160,
+    }
+
+    /// Emitted when the deadline expires while someone holds the potato
+    #[ink(event)]
+    pub struct PotatoBurned {
+        /// Game the burn happened in
+        #[ink(topic)]
+        game_id: GameId,
+        /// Index of the potato that burned; `None` when a capped game's
+        /// tie-break burns a player holding none
+        potato: Option<u32>,
+        /// Holder who got burned
+        #[ink(topic)]
+        holder: H160,
+        /// Block the burn was detected in
+        block: u32,
+    }
+
+    /// Emitted when a game hits the game-length cap
+    #[ink(event)]
+    pub struct GameCapped {
+        /// Game that hit the cap
+        #[ink(topic)]
+        game_id: GameId,
+        /// Players the tie-break burned
+        losers: Vec<H160>,
+        /// Rule that picked the loser
+        tie_break: TieBreak,
+        /// Blocks the game ran for, on the game clock
+        length: u64,
+    }
+
+    /// Emitted when a player picks their team in a team game
+    #[ink(event)]
+    pub struct TeamJoined {
+        /// Game that was joined
+        #[ink(topic)]
+        game_id: GameId,
+        /// Player who joined
+        #[ink(topic)]
+        player: H160,
+        /// Team they play for
+        team: u32,
+    }
+
+    /// Emitted when a burn counts against a team
+    #[ink(event)]
+    pub struct TeamBurned {
+        /// Game the burn happened in
+        #[ink(topic)]
+        game_id: GameId,
+        /// Holder who got burned
+        #[ink(topic)]
+        holder: H160,
+        /// Team the burn counts against
+        team: u32,
+        /// Burns the team has taken so far
+        burns: u32,
+    }
+
+    /// Emitted when an elimination or team game moves on to its next round
+    #[ink(event)]
+    pub struct RoundStarted {
+        /// Game the round belongs to
+        #[ink(topic)]
+        game_id: GameId,
+        /// Holder of the first potato at the start of the round
+        #[ink(topic)]
+        holder: H160,
+        /// Round number
+        round: u32,
+        /// Block the round started in
+        block: u32,
+        /// Players still in the game
+        survivors: u32,
+    }
+
+    /// Emitted when winnings or a refund are credited to a player's
+    /// claimable balance
+    #[ink(event)]
+    pub struct WinningsCredited {
+        /// Game the funds came from
+        #[ink(topic)]
+        game_id: GameId,
+        /// Player credited
+        #[ink(topic)]
+        player: H160,
+        /// Amount credited
+        amount: Balance,
+    }
+
+    /// Emitted when the caller of `check_deadline` is paid for a burn
+    #[ink(event)]
+    pub struct KeeperRewarded {
+        /// Game the burn happened in
+        #[ink(topic)]
+        game_id: GameId,
+        /// Account that triggered the burn
+        #[ink(topic)]
+        keeper: H160,
+        /// Amount credited from the pool
+        amount: Balance,
+    }
+
+    /// Emitted when the starter reveals a hidden fuse
+    #[ink(event)]
+    pub struct FuseRevealed {
+        /// Game the fuse belongs to
+        #[ink(topic)]
+        game_id: GameId,
+        /// Holder at the moment the fuse burned down
+        #[ink(topic)]
+        holder: H160,
+        /// Revealed fuse length, in the game's deadline unit
+        fuse: u64,
+    }
+
+    /// Emitted when a hidden fuse game ends without a reveal and the
+    /// starter's bond goes to the prize pool
+    #[ink(event)]
+    pub struct FuseSlashed {
+        /// Game the fuse belonged to
+        #[ink(topic)]
+        game_id: GameId,
+        /// Starter who failed to reveal
+        #[ink(topic)]
+        starter: H160,
+        /// Bond added to the pool
+        bond: Balance,
+    }
+
+    /// Emitted when a player withdraws their claimable balance
+    #[ink(event)]
+    pub struct Withdrawn {
+        /// Player who withdrew
+        #[ink(topic)]
+        player: H160,
+        /// Amount transferred
+        amount: Balance,
+    }
+
+    /// Emitted whenever a game finishes, either by a burn or manually
+    #[ink(event)]
+    pub struct GameEnded {
+        /// Game that ended
+        #[ink(topic)]
+        game_id: GameId,
+        /// Who ended the game
+        #[ink(topic)]
+        ended_by: H160,
+        /// Holder of the first potato at the moment the game ended
+        #[ink(topic)]
+        holder: Option<H160>,
+        /// Block the game ended in
+        block: u32,
+        /// Time that was left on the first potato's deadline, in the game's
+        /// deadline unit
+        remaining: u64,
+        /// Last player standing, if exactly one survived
+        winner: Option<H160>,
+    }
+
+    /// Emitted when the owner proposes a new owner
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        /// Current owner
+        #[ink(topic)]
+        owner: H160,
+        /// Account that must accept to become owner
+        #[ink(topic)]
+        pending_owner: H160,
+    }
+
+    /// Emitted when the proposed owner accepts ownership
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        /// Owner before the transfer
+        #[ink(topic)]
+        previous_owner: H160,
+        /// Owner after the transfer
+        #[ink(topic)]
+        new_owner: H160,
+    }
+
+    /// Emitted when the owner changes a contract-wide setting
+    #[ink(event)]
+    pub struct ConfigChanged {
+        /// Owner who made the change
+        #[ink(topic)]
+        owner: H160,
+        /// Setting and its new value
+        change: ConfigChange,
+    }
+
+    /// Emitted when play is paused
+    #[ink(event)]
+    pub struct Paused {
+        /// Owner or guardian who paused
+        #[ink(topic)]
+        by: H160,
+        /// Block the pause started in
+        block: u32,
+    }
+
+    /// Emitted when play resumes
+    #[ink(event)]
+    pub struct Unpaused {
+        /// Owner or guardian who unpaused
+        #[ink(topic)]
+        by: H160,
+        /// How long play was paused
+        paused_for: ClockReading,
+    }
+
+    /// Emitted when the owner replaces the contract code
+    #[ink(event)]
+    pub struct Upgraded {
+        /// Hash of the new code
+        #[ink(topic)]
+        code_hash: H256,
+    }
+
+    /// Emitted when storage is brought up to the current layout
+    #[ink(event)]
+    pub struct Migrated {
+        /// Storage version before the migration
+        from: u32,
+        /// Storage version after the migration
+        to: u32,
+    }
+
+    /// 🗃️ The single game the first version of the contract stored flat
+    ///
+    /// Field order and types match that version's storage exactly, so its
+    /// storage still decodes after a code swap and `migrate` can turn it
+    /// into a `Game`. Accounts there are `H160` addresses padded with zeros.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct LegacyGame {
         /// Current holder of the potato
-        current_holder: Option<AccountId>,
+        pub current_holder: Option<AccountId>,
         /// Block when potato was last passed
-        last_passed_block: u32,
+        pub last_passed_block: u32,
         /// Blocks until deadline
-        deadline_blocks: u32,
+        pub deadline_blocks: u32,
         /// Is game active?
-        active: bool,
+        pub active: bool,
         /// Who started the game
-        game_starter: Option<AccountId>,
+        pub game_starter: Option<AccountId>,
+    }
+
+    /// 📦 Contract Storage
+    ///
+    /// `legacy` is the only field kept in the root cell; everything else
+    /// lives in its own cell, so the root keeps the first version's layout.
+    #[ink(storage)]
+    pub struct Hotpotato {
+        /// Storage of the first version, emptied by `migrate`
+        legacy: LegacyGame,
+        /// All games ever created, by id
+        games: Mapping<GameId, Game>,
+        /// Id the next created game receives
+        next_game_id: Lazy<GameId>,
+        /// Deadline for newly created games that do not pick their own
+        deadline: Lazy<Deadline>,
+        /// Dense list of games that have not ended, `0..active_game_count`
+        active_games: Mapping<u32, GameId>,
+        /// Position of each unfinished game in `active_games`
+        active_game_slots: Mapping<GameId, u32>,
+        /// Number of games in the lobby or running
+        active_game_count: Lazy<u32>,
+        /// Winnings and refunds each player can withdraw
+        claimable: Mapping<H160, Balance>,
+        /// Who held the potato when, for hidden fuse games
+        holder_log: Mapping<(GameId, u32), HolderChange>,
+        /// Entropy each player committed to per game
+        entropy: Mapping<(GameId, H160), Entropy>,
+        /// Account allowed to change the contract settings
+        owner: Lazy<H160>,
+        /// Account proposed as the next owner, until it accepts
+        pending_owner: Lazy<Option<H160>>,
+        /// Bounds new games are validated against
+        settings: Lazy<Settings>,
+        /// Account that may pause play besides the owner
+        guardian: Lazy<Option<H160>>,
+        /// When play was paused, while it is
+        paused_at: Lazy<Option<ClockReading>>,
+        /// Time spent paused so far, hidden from every game clock
+        paused_total: Lazy<ClockReading>,
+        /// Layout version the storage was last written in; 0 for the flat
+        /// layout of the first version
+        storage_version: Lazy<u32>,
+        /// Lifetime record of every player
+        stats: Mapping<H160, PlayerStats>,
+        /// Streak and score of every player
+        standings: Mapping<H160, Standing>,
+        /// Top players per metric, best first
+        leaderboards: Mapping<LeaderboardMetric, Vec<LeaderboardEntry>>,
+        /// Entries each leaderboard keeps; `DEFAULT_LEADERBOARD_SIZE` if unset
+        leaderboard_size: Lazy<u32>,
+        /// Elo rating of every player who finished a game
+        ratings: Mapping<H160, u32>,
+        /// Every pass of every game, by game and pass index
+        pass_history: Mapping<(GameId, u32), PassRecord>,
+        /// Extent of each game's pass history
+        pass_logs: Mapping<GameId, PassLog>,
+        /// Rules every pass must follow; `PassRules::default()` if unset
+        pass_rules: Lazy<PassRules>,
+        /// How often each player received the potato per game
+        receives: Mapping<(GameId, H160), u32>,
+        /// Minimum hold and game-length cap; `TimeLimits::default()` if unset
+        time_limits: Lazy<TimeLimits>,
+        /// Block each running game started in, on the game clock
+        started_at: Mapping<GameId, u64>,
+        /// Team setup of every team game
+        team_configs: Mapping<GameId, TeamConfig>,
+        /// Team each player of a team game plays for
+        teams: Mapping<(GameId, H160), u32>,
+        /// Burns taken by each team of a team game, by team number
+        team_burns: Mapping<GameId, Vec<u32>>,
+        /// Every potato of every game, by game and potato index
+        potatoes: Mapping<(GameId, u32), Potato>,
+        /// Block the holder of each potato of a running game received it,
+        /// on the game clock
+        held_since: Mapping<(GameId, u32), u64>,
+        /// Session keys by delegate and game
+        session_keys: Mapping<(H160, GameId), SessionKey>,
     }
 
-    impl Hotpotato {
-        /// Constructor - Initialize the hot potato game
-        #[ink(constructor)]
-        pub fn new(deadline_blocks: u32) -> Self {
-            Self {
-                current_holder: None,
-                last_passed_block: 0,
-                deadline_blocks,
-                active: false,
-                game_starter: None,
+    impl Hotpotato {
+        /// Constructor - Initialize the hot potato game with a deadline
+        /// measured in blocks
+        #[ink(constructor)]
+        pub fn new(deadline_blocks: u32) -> Self {
+            Self::new_with_unit(u64::from(deadline_blocks), DeadlineUnit::Blocks)
+        }
+
+        /// Constructor - Initialize the hot potato game with a deadline of
+        /// `window` measured in `unit`; the caller becomes the owner
+        #[ink(constructor)]
+        pub fn new_with_unit(window: u64, unit: DeadlineUnit) -> Self {
+            let mut contract = Self {
+                legacy: LegacyGame::default(),
+                games: Mapping::default(),
+                next_game_id: Lazy::new(),
+                deadline: Lazy::new(),
+                active_games: Mapping::default(),
+                active_game_slots: Mapping::default(),
+                active_game_count: Lazy::new(),
+                claimable: Mapping::default(),
+                holder_log: Mapping::default(),
+                entropy: Mapping::default(),
+                owner: Lazy::new(),
+                pending_owner: Lazy::new(),
+                settings: Lazy::new(),
+                guardian: Lazy::new(),
+                paused_at: Lazy::new(),
+                paused_total: Lazy::new(),
+                storage_version: Lazy::new(),
+                stats: Mapping::default(),
+                standings: Mapping::default(),
+                leaderboards: Mapping::default(),
+                leaderboard_size: Lazy::new(),
+                ratings: Mapping::default(),
+                pass_history: Mapping::default(),
+                pass_logs: Mapping::default(),
+                pass_rules: Lazy::new(),
+                receives: Mapping::default(),
+                time_limits: Lazy::new(),
+                started_at: Mapping::default(),
+                team_configs: Mapping::default(),
+                teams: Mapping::default(),
+                team_burns: Mapping::default(),
+                potatoes: Mapping::default(),
+                held_since: Mapping::default(),
+                session_keys: Mapping::default(),
+            };
+            contract.deadline.set(&Deadline::constant(window, unit));
+            contract.owner.set(&Self::env().caller());
+            contract.storage_version.set(&STORAGE_VERSION);
+            contract
+        }
+
+        /// Helper function to read both chain clocks
+        fn clock(&self) -> ClockReading {
+            ClockReading {
+                blocks: u64::from(self.env().block_number()),
+                millis: self.env().block_timestamp(),
+            }
+        }
+
+        /// Helper function to read the game clock `unit` is measured on
+        ///
+        /// Game clocks stand still while play is paused, so no deadline,
+        /// fuse or reveal window runs out during a pause.
+        fn now(&self, unit: DeadlineUnit) -> u64 {
+            let clock = self
+                .paused_at
+                .get_or_default()
+                .unwrap_or_else(|| self.clock());
+            clock
+                .get(unit)
+                .saturating_sub(self.paused_total.get_or_default().get(unit))
+        }
+
+        /// Helper function to reject calls while play is paused
+        fn ensure_not_paused(&self) -> Result<()> {
+            if self.paused_at.get_or_default().is_some() {
+                return Err(HotPotatoError::ContractPaused);
+            }
+            Ok(())
+        }
+
+        /// Helper function to restrict a message to the owner or guardian
+        fn ensure_guardian(&self) -> Result<()> {
+            let caller = self.env().caller();
+            if caller != self.owner.get_or_default()
+                && self.guardian.get_or_default() != Some(caller)
+            {
+                return Err(HotPotatoError::OnlyGuardian);
+            }
+            Ok(())
+        }
+
+        /// Helper function to reject the zero address as a recipient
+        fn ensure_valid_recipient(to: &H160) -> Result<()> {
+            if *to == H160::zero() {
+                return Err(HotPotatoError::InvalidRecipient);
+            }
+            Ok(())
+        }
+
+        /// Helper function to read the deadline of games that do not pick
+        /// their own; the first version's deadline until `migrate` has run
+        fn default_deadline(&self) -> Deadline {
+            self.deadline.get().unwrap_or_else(|| {
+                Deadline::constant(u64::from(self.legacy.deadline_blocks), DeadlineUnit::Blocks)
+            })
+        }
+
+        /// Helper function to restrict a message to the owner
+        fn ensure_owner(&self) -> Result<()> {
+            if self.env().caller() != self.owner.get_or_default() {
+                return Err(HotPotatoError::OnlyOwner);
+            }
+            Ok(())
+        }
+
+        /// Helper function to validate, store and announce new settings
+        fn update_settings(&mut self, settings: Settings, change: ConfigChange) -> Result<()> {
+            self.ensure_owner()?;
+            settings.validate()?;
+            self.settings.set(&settings);
+            self.env().emit_event(ConfigChanged {
+                owner: self.owner.get_or_default(),
+                change,
+            });
+            Ok(())
+        }
+
+        /// Helper function to load a game or fail with `GameNotFound`
+        fn game(&self, game_id: GameId) -> Result<Game> {
+            self.games.get(game_id).ok_or(HotPotatoError::GameNotFound)
+        }
+
+        /// Helper function to load a game that is currently running
+        fn active_game(&self, game_id: GameId) -> Result<Game> {
+            let game = self.game(game_id)?;
+            if !game.is_active() {
+                return Err(HotPotatoError::GameNotActive);
+            }
+            Ok(game)
+        }
+
+        /// Helper function to load a game that is still accepting players
+        fn lobby_game(&self, game_id: GameId) -> Result<Game> {
+            let game = self.game(game_id)?;
+            if game.status != GameStatus::Lobby {
+                return Err(HotPotatoError::NotInLobby);
+            }
+            Ok(game)
+        }
+
+        /// 🏠 Open a lobby for a new game and return its id
+        #[ink(message)]
+        pub fn create_game(&mut self, config: GameConfig) -> Result<GameId> {
+            if config.mode == GameMode::Teams {
+                return Err(HotPotatoError::InvalidTeamConfig);
+            }
+            self.open_game(config)
+        }
+
+        /// 🛡️ Open a lobby for a new team game and return its id
+        ///
+        /// `config.mode` must be `GameMode::Teams`; players pick their team
+        /// with `join_team`.
+        #[ink(message)]
+        pub fn create_team_game(
+            &mut self,
+            config: GameConfig,
+            teams: TeamConfig,
+        ) -> Result<GameId> {
+            if config.mode != GameMode::Teams {
+                return Err(HotPotatoError::InvalidTeamConfig);
+            }
+            teams.validate()?;
+
+            let game_id = self.open_game(config)?;
+            let burns: Vec<u32> = core::iter::repeat_n(0, teams.teams as usize).collect();
+            self.team_burns.insert(game_id, &burns);
+            self.team_configs.insert(game_id, &teams);
+            Ok(game_id)
+        }
+
+        /// Helper function to open a lobby once the mode-specific checks passed
+        fn open_game(&mut self, config: GameConfig) -> Result<GameId> {
+            self.ensure_not_paused()?;
+            config.validate(&self.settings.get_or_default())?;
+            let deadline = config.deadline.unwrap_or(self.default_deadline());
+            if !self.time_limits.get().unwrap_or_default().fits(&deadline) {
+                return Err(HotPotatoError::InvalidDeadline);
+            }
+
+            let caller = self.env().caller();
+            let game_id = self.next_game_id.get_or_default();
+
+            let game = Game {
+                starter: caller,
+                deadline,
+                status: GameStatus::Lobby,
+                players: Vec::new(),
+                eliminated: Vec::new(),
+                config: config.clone(),
+                round: 0,
+                round_started_block: 0,
+                winner: None,
+                pool: 0,
+                fuse: None,
+                beacon: Beacon::default(),
+            };
+            self.games.insert(game_id, &game);
+            self.next_game_id.set(&(game_id + 1));
+            self.track_active(game_id);
+
+            self.env().emit_event(GameCreated {
+                game_id,
+                starter: caller,
+                min_players: config.min_players,
+                max_players: config.max_players,
+                mode: config.mode,
+                entry_fee: config.entry_fee,
+            });
+
+            Ok(game_id)
+        }
+
+        /// 🙋 Register the caller for a game that has not started yet
+        ///
+        /// The call must carry exactly the game's entry fee, which goes into
+        /// the prize pool. `entropy_commitment` commits to the secret the
+        /// player reveals once the lobby closes, see `entropy_commitment`.
+        #[ink(message, payable)]
+        pub fn join_game(&mut self, game_id: GameId, entropy_commitment: Hash) -> Result<()> {
+            self.join(game_id, None, entropy_commitment)
+        }
+
+        /// 🛡️ Register the caller for `team` of a team game that has not
+        /// started yet, on the same terms as `join_game`
+        #[ink(message, payable)]
+        pub fn join_team(
+            &mut self,
+            game_id: GameId,
+            team: u32,
+            entropy_commitment: Hash,
+        ) -> Result<()> {
+            self.join(game_id, Some(team), entropy_commitment)
+        }
+
+        /// Helper function to register the caller, on a team in team games
+        fn join(
+            &mut self,
+            game_id: GameId,
+            team: Option<u32>,
+            entropy_commitment: Hash,
+        ) -> Result<()> {
+            self.ensure_not_paused()?;
+            let mut game = self.lobby_game(game_id)?;
+            match (self.team_configs.get(game_id), team) {
+                (Some(config), Some(team)) if team >= config.teams => {
+                    return Err(HotPotatoError::InvalidTeam)
+                }
+                (Some(_), None) => return Err(HotPotatoError::TeamRequired),
+                (None, Some(_)) => return Err(HotPotatoError::NotTeamGame),
+                _ => {}
+            }
+
+            let caller = self.env().caller();
+            if game.is_player(&caller) {
+                return Err(HotPotatoError::AlreadyRegistered);
+            }
+            if self.live_session_key(game_id, caller).is_some() {
+                return Err(HotPotatoError::DelegateCannotJoin);
+            }
+            if game.players.len() as u32 >= game.config.max_players {
+                return Err(HotPotatoError::LobbyFull);
+            }
+            if self.env().transferred_value() != U256::from(game.config.entry_fee) {
+                return Err(HotPotatoError::IncorrectEntryFee);
+            }
+
+            game.players.push(caller);
+            game.pool += game.config.entry_fee;
+            self.games.insert(game_id, &game);
+            self.entropy.insert(
+                (game_id, caller),
+                &Entropy {
+                    commitment: entropy_commitment,
+                    revealed: false,
+                },
+            );
+
+            self.env().emit_event(PlayerJoined {
+                game_id,
+                player: caller,
+            });
+            if let Some(team) = team {
+                self.teams.insert((game_id, caller), &team);
+                self.env().emit_event(TeamJoined {
+                    game_id,
+                    player: caller,
+                    team,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// 🚪 Withdraw the caller from a game that has not started yet and
+        /// credit their entry fee back
+        #[ink(message)]
+        pub fn leave_game(&mut self, game_id: GameId) -> Result<()> {
+            let mut game = self.lobby_game(game_id)?;
+
+            let caller = self.env().caller();
+            if !game.is_player(&caller) {
+                return Err(HotPotatoError::NotRegistered);
+            }
+
+            game.players.retain(|player| *player != caller);
+            game.pool -= game.config.entry_fee;
+            self.games.insert(game_id, &game);
+            self.entropy.remove((game_id, caller));
+            self.teams.remove((game_id, caller));
+
+            self.env().emit_event(PlayerLeft {
+                game_id,
+                player: caller,
+            });
+
+            self.credit(game_id, caller, game.config.entry_fee);
+
+            Ok(())
+        }
+
+        /// 🔒 Stop accepting players and let them reveal their entropy
+        ///
+        /// Hidden fuse games take the starter's `fuse_commitment` here, before
+        /// any entropy is revealed, and must be called with the fuse bond
+        /// attached. Players get one deadline window to reveal.
+        #[ink(message, payable)]
+        pub fn close_lobby(
+            &mut self,
+            game_id: GameId,
+            fuse_commitment: Option<Hash>,
+        ) -> Result<()> {
+            self.ensure_not_paused()?;
+            let mut game = self.lobby_game(game_id)?;
+
+            let caller = self.env().caller();
+            if game.starter != caller {
+                return Err(HotPotatoError::OnlyStarter);
+            }
+            let players = game.players.len() as u32;
+            if players < game.config.min_players || players <= game.config.potatoes {
+                return Err(HotPotatoError::NotEnoughPlayers);
+            }
+            if !self.teams_ready(game_id, &game) {
+                return Err(HotPotatoError::NotEnoughTeams);
+            }
+
+            let bond = game.config.fuse.as_ref().map_or(0, |fuse| fuse.bond);
+            if self.env().transferred_value() != U256::from(bond) {
+                return Err(HotPotatoError::IncorrectFuseBond);
+            }
+            game.fuse = match (&game.config.fuse, fuse_commitment) {
+                (Some(_), Some(commitment)) => Some(HiddenFuse {
+                    commitment,
+                    lit_at: 0,
+                    expires_by: 0,
+                    reveal_by: 0,
+                    bond,
+                    holder_changes: 0,
+                    revealed: None,
+                }),
+                (Some(_), None) => return Err(HotPotatoError::FuseCommitmentRequired),
+                (None, Some(_)) => return Err(HotPotatoError::NoHiddenFuse),
+                (None, None) => None,
+            };
+
+            let reveal_by = self
+                .now(game.deadline.unit)
+                .saturating_add(game.deadline.window);
+            game.beacon.reveal_by = reveal_by;
+            game.status = GameStatus::Seeding;
+            self.games.insert(game_id, &game);
+
+            self.env().emit_event(LobbyClosed {
+                game_id,
+                players: game.players.len() as u32,
+                reveal_by,
+            });
+
+            Ok(())
+        }
+
+        /// 🎲 Reveal the secret the caller committed to when joining
+        #[ink(message)]
+        pub fn reveal_entropy(&mut self, game_id: GameId, secret: Hash) -> Result<()> {
+            self.ensure_not_paused()?;
+            let mut game = self.game(game_id)?;
+            if game.status != GameStatus::Seeding {
+                return Err(HotPotatoError::NotSeeding);
+            }
+
+            let caller = self.env().caller();
+            let mut entropy = self
+                .entropy
+                .get((game_id, caller))
+                .filter(|_| game.is_player(&caller))
+                .ok_or(HotPotatoError::NotRegistered)?;
+            if entropy.revealed {
+                return Err(HotPotatoError::AlreadyRevealed);
+            }
+            if self.now(game.deadline.unit) > game.beacon.reveal_by {
+                return Err(HotPotatoError::RevealWindowClosed);
+            }
+            if entropy_commitment(&secret, caller) != entropy.commitment {
+                return Err(HotPotatoError::InvalidEntropyReveal);
+            }
+
+            entropy.revealed = true;
+            self.entropy.insert((game_id, caller), &entropy);
+            game.beacon.mix = mix_entropy(&game.beacon.mix, &secret);
+            game.beacon.reveals += 1;
+            self.games.insert(game_id, &game);
+
+            self.env().emit_event(EntropyRevealed {
+                game_id,
+                player: caller,
+            });
+
+            Ok(())
+        }
+
+        /// 🚀 Launch a game once every player revealed or the reveal window
+        /// is over, handing the potato to a player drawn from the beacon
+        ///
+        /// Anyone may call this. Players who never revealed are dropped and
+        /// forfeit their entry fee to the pool. If that leaves fewer than
+        /// `min_players`, the game is cancelled instead and the pool is split
+        /// between the players who did reveal; if nobody revealed, everyone
+        /// is refunded.
+        #[ink(message)]
+        pub fn start_game(&mut self, game_id: GameId) -> Result<()> {
+            self.ensure_not_paused()?;
+            let mut game = self.game(game_id)?;
+            if game.status != GameStatus::Seeding {
+                return Err(HotPotatoError::NotSeeding);
+            }
+
+            let current_block = self.env().block_number();
+            let now = self.now(game.deadline.unit);
+            let all_revealed = game.beacon.reveals as usize == game.players.len();
+            if !all_revealed && now <= game.beacon.reveal_by {
+                return Err(HotPotatoError::EntropyPending);
+            }
+
+            let caller = self.env().caller();
+            if game.beacon.reveals > 0 {
+                self.drop_withholders(game_id, &mut game);
+            }
+            let players = game.players.len() as u32;
+            if game.beacon.reveals == 0
+                || players < game.config.min_players
+                || players <= game.config.potatoes
+                || !self.teams_ready(game_id, &game)
+            {
+                // Not the starter's fault, so the fuse bond goes back
+                if let Some(fuse) = game.fuse.as_mut() {
+                    let bond = core::mem::take(&mut fuse.bond);
+                    self.credit(game_id, game.starter, bond);
+                }
+                self.finish_game(game_id, game, caller, 0);
+                return Ok(());
+            }
+
+            let seed = beacon_seed(game_id, &game.beacon.mix);
+            let seat = seat_for(&seed, game.players.len());
+            let holder = game.players[seat];
+            game.beacon.seed = Some(seed);
+
+            if let (Some(fuse), Some(bounds)) = (game.fuse.as_mut(), &game.config.fuse) {
+                fuse.lit_at = now;
+                fuse.expires_by = now.saturating_add(bounds.max_fuse);
+                fuse.reveal_by = fuse.expires_by.saturating_add(bounds.reveal_window);
+            }
+
+            for player in &game.players {
+                self.update_stats(*player, |stats| stats.games_played += 1);
+            }
+
+            // Update contract state
+            self.deal_potatoes(game_id, &mut game, seat, now);
+            // A hidden fuse bounds the game already, so only open games are
+            // held to the game-length cap
+            if game.fuse.is_none() {
+                self.started_at
+                    .insert(game_id, &self.now(DeadlineUnit::Blocks));
+            }
+            self.log_holder(game_id, &mut game, now, holder);
+            game.status = GameStatus::Active;
+            game.round = 1;
+            game.round_started_block = current_block;
+            self.games.insert(game_id, &game);
+
+            // 🎯 EMIT EVENT
+            self.env().emit_event(GameStarted {
+                game_id,
+                starter: game.starter,
+                holder,
+                block: current_block,
+                remaining: self
+                    .potato(game_id, &game, 0)
+                    .map_or(0, |potato| game.window_of(&potato)),
+            });
+
+            Ok(())
+        }
+
+        /// Helper function to drop every player who did not reveal their
+        /// entropy; their entry fees stay in the pool
+        fn drop_withholders(&mut self, game_id: GameId, game: &mut Game) {
+            let withholders: Vec<H160> = game
+                .players
+                .iter()
+                .copied()
+                .filter(|player| {
+                    !self
+                        .entropy
+                        .get((game_id, *player))
+                        .is_some_and(|entropy| entropy.revealed)
+                })
+                .collect();
+            for player in withholders {
+                game.players.retain(|p| *p != player);
+                self.env().emit_event(EntropyWithheld { game_id, player });
+            }
+        }
+
+        /// 🔄 Pass the potato to another account
+        /// Shows conditional event emission
+        ///
+        /// A live session key may call this for the holder; the pass counts
+        /// as the holder's own.
+        #[ink(message)]
+        pub fn pass_potato(&mut self, game_id: GameId, potato: u32, to: H160) -> Result<()> {
+            self.ensure_not_paused()?;
+            let mut game = self.active_game(game_id)?;
+            let mut passed = self
+                .potato(game_id, &game, potato)
+                .ok_or(HotPotatoError::InvalidPotato)?;
+
+            let (caller, delegate) = self.acting_for(game_id);
+            if passed.holder != Some(caller) {
+                return Err(HotPotatoError::NotCurrentHolder);
+            }
+            Self::ensure_valid_recipient(&to)?;
+            game.ensure_can_receive(&to)?;
+            if self
+                .potato_of(game_id, &game, to)
+                .is_some_and(|held| held != potato)
+            {
+                return Err(HotPotatoError::AlreadyHoldsPotato);
+            }
+            self.ensure_other_team(game_id, caller, to)?;
+            self.ensure_pass_allowed(game_id, caller, to)?;
+            self.ensure_within_time_limits(game_id, potato)?;
+
+            let now = self.now(game.deadline.unit);
+
+            // Check deadline
+            if now > game.deadline_of(&passed) {
+                return Err(HotPotatoError::DeadlinePassed);
+            }
+
+            // Calculate remaining time
+            let remaining = game.remaining_of(&passed, now);
+
+            let held = self.end_hold(game_id, potato, passed.holder);
+            self.update_stats(caller, |stats| {
+                stats.passes += 1;
+                stats.fastest_pass = Some(stats.fastest_pass.map_or(held, |f| f.min(held)));
+            });
+
+            // Update state - the next holder's window shrinks with every pass
+            passed.holder = Some(to);
+            passed.last_passed_at = now;
+            passed.pass_count += 1;
+            self.potatoes.insert((game_id, potato), &passed);
+            self.log_holder(game_id, &mut game, now, to);
+            self.games.insert(game_id, &game);
+
+            let mut log = self.pass_logs.get(game_id).unwrap_or_default();
+            self.pass_history.insert(
+                (game_id, log.len),
+                &PassRecord {
+                    potato,
+                    from: caller,
+                    to,
+                    block: self.env().block_number(),
+                    at: now,
+                    remaining,
+                },
+            );
+            log.len += 1;
+            self.pass_logs.insert(game_id, &log);
+            let received = self.receives.get((game_id, to)).unwrap_or_default();
+            self.receives.insert((game_id, to), &(received + 1));
+
+            // 🎯 EMIT EVENT
+            self.env().emit_event(PotatoPassed {
+                game_id,
+                potato,
+                from: caller,
+                to,
+                block: self.env().block_number(),
+                remaining,
+                window: game.window_of(&passed),
+                delegate,
+            });
+
+            Ok(())
+        }
+
+        /// 🔑 Let `delegate` pass the potato for the caller in `game_id` up to
+        /// and including block `expires_at`
+        ///
+        /// Authorizing the same delegate again replaces its expiry.
+        #[ink(message)]
+        pub fn authorize_session_key(
+            &mut self,
+            game_id: GameId,
+            delegate: H160,
+            expires_at: u32,
+        ) -> Result<()> {
+            let game = self.game(game_id)?;
+            if game.status == GameStatus::Ended {
+                return Err(HotPotatoError::GameNotActive);
+            }
+
+            let caller = self.env().caller();
+            if !game.is_player(&caller) {
+                return Err(HotPotatoError::NotRegistered);
+            }
+            if delegate == H160::zero() || self.is_registered(game_id, delegate) {
+                return Err(HotPotatoError::InvalidDelegate);
+            }
+            let current_block = self.env().block_number();
+            if expires_at <= current_block {
+                return Err(HotPotatoError::InvalidExpiry);
+            }
+            if let Some(key) = self.live_session_key(game_id, delegate) {
+                if key.principal != caller {
+                    return Err(HotPotatoError::SessionKeyTaken);
+                }
+            }
+
+            self.session_keys.insert(
+                (delegate, game_id),
+                &SessionKey {
+                    principal: caller,
+                    expires_at,
+                },
+            );
+            self.env().emit_event(SessionKeyAuthorized {
+                game_id,
+                principal: caller,
+                delegate,
+                expires_at,
+            });
+            Ok(())
+        }
+
+        /// 🔑 Withdraw a session key the caller authorized
+        #[ink(message)]
+        pub fn revoke_session_key(&mut self, game_id: GameId, delegate: H160) -> Result<()> {
+            let caller = self.env().caller();
+            let key = self
+                .session_keys
+                .get((delegate, game_id))
+                .filter(|key| key.principal == caller)
+                .ok_or(HotPotatoError::NoSessionKey)?;
+
+            self.session_keys.remove((delegate, game_id));
+            self.env().emit_event(SessionKeyRevoked {
+                game_id,
+                principal: key.principal,
+                delegate,
+            });
+            Ok(())
+        }
+
+        /// Helper function to find who the caller acts for in `game_id`: the
+        /// principal of a live session key, or the caller themselves
+        fn acting_for(&self, game_id: GameId) -> (H160, Option<H160>) {
+            let caller = self.env().caller();
+            match self.live_session_key(game_id, caller) {
+                Some(key) => (key.principal, Some(caller)),
+                None => (caller, None),
+            }
+        }
+
+        /// Helper function to load the session key `delegate` holds for a
+        /// game, unless it has expired
+        fn live_session_key(&self, game_id: GameId, delegate: H160) -> Option<SessionKey> {
+            self.session_keys
+                .get((delegate, game_id))
+                .filter(|key| self.env().block_number() <= key.expires_at)
+        }
+
+        /// Helper function to check a pass against the pass rules
+        fn ensure_pass_allowed(&self, game_id: GameId, from: H160, to: H160) -> Result<()> {
+            let rules = self.pass_rules.get().unwrap_or_default();
+            if from == to && !rules.allow_self_pass {
+                return Err(HotPotatoError::SelfPass);
+            }
+
+            let log = self.pass_logs.get(game_id).unwrap_or_default();
+            let recent = log.len.saturating_sub(rules.pass_back_window)..log.len;
+            if recent
+                .filter_map(|index| self.pass_history.get((game_id, index)))
+                .any(|record| record.from == to)
+            {
+                return Err(HotPotatoError::PassBackTooSoon);
+            }
+
+            let received = self.receives.get((game_id, to)).unwrap_or_default();
+            if rules.max_receives > 0 && received >= rules.max_receives {
+                return Err(HotPotatoError::ReceiveLimitReached);
+            }
+            Ok(())
+        }
+
+        /// Helper function to check the minimum hold and the game-length cap
+        /// before a pass
+        fn ensure_within_time_limits(&self, game_id: GameId, potato: u32) -> Result<()> {
+            if self.cap_reached(game_id) {
+                return Err(HotPotatoError::GameLengthCapped);
+            }
+
+            let min_hold = self.time_limits.get().unwrap_or_default().min_hold;
+            let since = self.hold_start(game_id, potato);
+            if self.now(DeadlineUnit::Blocks).saturating_sub(since) < min_hold {
+                return Err(HotPotatoError::HoldTooShort);
+            }
+            Ok(())
+        }
+
+        /// Helper function to get how long a running game has gone on, in
+        /// blocks on the game clock; `None` if it is not held to the cap
+        fn game_length(&self, game_id: GameId) -> Option<u64> {
+            let started = self.started_at.get(game_id)?;
+            Some(self.now(DeadlineUnit::Blocks).saturating_sub(started))
+        }
+
+        /// Helper function to check whether a game ran past the game-length
+        /// cap
+        fn cap_reached(&self, game_id: GameId) -> bool {
+            let cap = self.time_limits.get().unwrap_or_default().max_game_length;
+            cap > 0 && self.game_length(game_id).is_some_and(|length| length > cap)
+        }
+
+        /// Helper function to end a game that hit the game-length cap,
+        /// burning whoever the tie-break picks
+        fn cap_game(&mut self, game_id: GameId, mut game: Game, caller: H160) {
+            let tie_break = self.time_limits.get().unwrap_or_default().tie_break;
+            let losers: Vec<H160> = match tie_break {
+                TieBreak::Holder => self
+                    .all_potatoes(game_id, &game)
+                    .iter()
+                    .filter_map(|potato| potato.holder)
+                    .collect(),
+                TieBreak::MostReceived => self.most_received(game_id, &game).into_iter().collect(),
+                TieBreak::Split => Vec::new(),
+            };
+
+            // 🎯 EMIT EVENT - the cap, then a burn per loser
+            self.env().emit_event(GameCapped {
+                game_id,
+                losers: losers.clone(),
+                tie_break,
+                length: self.game_length(game_id).unwrap_or_default(),
+            });
+
+            for loser in losers {
+                self.env().emit_event(PotatoBurned {
+                    game_id,
+                    potato: self.potato_of(game_id, &game, loser),
+                    holder: loser,
+                    block: self.env().block_number(),
+                });
+                self.burn(game_id, &mut game, loser);
+                self.reward_keeper(game_id, &mut game, caller, loser);
+            }
+
+            let remaining = self.remaining_on(game_id, &game, 0);
+            self.finish_game(game_id, game, caller, remaining);
+        }
+
+        /// Helper function to find the player who received a potato most
+        /// often, preferring current holders and then the earliest seat on
+        /// ties
+        fn most_received(&self, game_id: GameId, game: &Game) -> Option<H160> {
+            let holders: Vec<H160> = self
+                .all_potatoes(game_id, game)
+                .iter()
+                .filter_map(|potato| potato.holder)
+                .collect();
+            let mut loser: Option<(H160, (u32, bool))> = None;
+            for player in &game.players {
+                let received = self.receives.get((game_id, *player)).unwrap_or_default();
+                let rank = (received, holders.contains(player));
+                if loser.is_none_or(|(_, most)| rank > most) {
+                    loser = Some((*player, rank));
+                }
+            }
+            loser.map(|(player, _)| player)
+        }
+
+        /// Helper function to knock out a burned player, or to count the burn
+        /// against their team in team games; returns the seat the next round
+        /// starts from
+        fn burn(&mut self, game_id: GameId, game: &mut Game, player: H160) -> usize {
+            self.update_stats(player, |stats| stats.burns += 1);
+            if game.config.mode != GameMode::Teams {
+                return game.eliminate(player);
+            }
+
+            let team = self.teams.get((game_id, player)).unwrap_or_default();
+            let mut burns = self.team_burns.get(game_id).unwrap_or_default();
+            if let Some(taken) = burns.get_mut(team as usize) {
+                *taken += 1;
+                self.env().emit_event(TeamBurned {
+                    game_id,
+                    holder: player,
+                    team,
+                    burns: *taken,
+                });
+            }
+            self.team_burns.insert(game_id, &burns);
+
+            // Nobody leaves a team game, so the next seat starts the round
+            let seat = game.players.iter().position(|p| *p == player);
+            seat.map_or(0, |seat| seat + 1)
+        }
+
+        /// Helper function to check that `to` plays for another team than
+        /// `from` in team games
+        fn ensure_other_team(&self, game_id: GameId, from: H160, to: H160) -> Result<()> {
+            if self.team_configs.contains(game_id)
+                && self.teams.get((game_id, from)) == self.teams.get((game_id, to))
+            {
+                return Err(HotPotatoError::SameTeam);
+            }
+            Ok(())
+        }
+
+        /// Helper function to check that a team game has players on at least
+        /// two teams; other games always pass
+        fn teams_ready(&self, game_id: GameId, game: &Game) -> bool {
+            if !self.team_configs.contains(game_id) {
+                return true;
+            }
+            let first = game
+                .players
+                .first()
+                .and_then(|player| self.teams.get((game_id, *player)));
+            game.players
+                .iter()
+                .any(|player| self.teams.get((game_id, *player)) != first)
+        }
+
+        /// Helper function to check whether a team game played all its rounds
+        /// or a team took the maximum burns
+        fn team_game_over(&self, game_id: GameId, game: &Game) -> bool {
+            let Some(config) = self.team_configs.get(game_id) else {
+                return true;
+            };
+            let burns = self.team_burns.get(game_id).unwrap_or_default();
+            game.round >= config.rounds
+                || (config.max_burns > 0 && burns.iter().any(|b| *b >= config.max_burns))
+        }
+
+        /// Helper function to split a finished team game's players into the
+        /// members of the teams with the fewest burns and everyone else, the
+        /// most burned team first
+        fn team_result(&self, game_id: GameId, game: &Game) -> (Vec<H160>, Vec<H160>) {
+            let burns = self.team_burns.get(game_id).unwrap_or_default();
+            let burns_of = |player: &H160| {
+                let team = self.teams.get((game_id, *player)).unwrap_or_default();
+                burns.get(team as usize).copied().unwrap_or_default()
+            };
+            let fewest = game.players.iter().map(burns_of).min().unwrap_or_default();
+
+            let (winners, mut losers): (Vec<H160>, Vec<H160>) = game
+                .players
+                .iter()
+                .partition(|player| burns_of(player) == fewest);
+            losers.sort_by_key(|player| core::cmp::Reverse(burns_of(player)));
+            (winners, losers)
+        }
+
+        /// 💥 Check deadline and burn potato if expired
+        /// Demonstrates multiple event emissions in one function
+        #[ink(message)]
+        pub fn check_deadline(&mut self, game_id: GameId) -> Result<bool> {
+            let mut game = self.active_game(game_id)?;
+
+            let current_block = self.env().block_number();
+            let now = self.now(game.deadline.unit);
+
+            // A hidden fuse only burns through `reveal_fuse`; past the reveal
+            // window the game ends and the starter's bond is slashed
+            if let Some(fuse) = &game.fuse {
+                if now <= fuse.reveal_by {
+                    return Ok(false);
+                }
+                let caller = self.env().caller();
+                self.finish_game(game_id, game, caller, 0);
+                return Ok(true);
+            }
+
+            let mut burned = Vec::new();
+            for index in 0..game.config.potatoes {
+                let Some(potato) = self.potato(game_id, &game, index) else {
+                    continue;
+                };
+                if now > game.deadline_of(&potato) {
+                    let holder = potato.holder.ok_or(HotPotatoError::NoHolder)?;
+                    burned.push((index, holder));
+                }
+            }
+
+            if !burned.is_empty() {
+                let caller = self.env().caller();
+                let mut first_seat = None;
+                for (index, holder) in burned {
+                    // 🎯 EMIT EVENT - each burn, then either a new round or
+                    // the end
+                    self.env().emit_event(PotatoBurned {
+                        game_id,
+                        potato: Some(index),
+                        holder,
+                        block: current_block,
+                    });
+
+                    // The holder is out, or their team takes the burn
+                    self.end_hold(game_id, index, Some(holder));
+                    let seat = self.burn(game_id, &mut game, holder);
+                    first_seat.get_or_insert(seat);
+                    self.reward_keeper(game_id, &mut game, caller, holder);
+                }
+
+                let next_round = match game.config.mode {
+                    GameMode::Classic => false,
+                    GameMode::Elimination => game.players.len() as u32 > game.config.potatoes,
+                    GameMode::Teams => !self.team_game_over(game_id, &game),
+                };
+                if next_round {
+                    let seat = first_seat.unwrap_or_default();
+                    self.start_next_round(game_id, game, seat, current_block);
+                } else {
+                    self.finish_game(game_id, game, caller, 0);
+                }
+                return Ok(true);
+            }
+
+            if self.cap_reached(game_id) {
+                let caller = self.env().caller();
+                self.cap_game(game_id, game, caller);
+                return Ok(true);
+            }
+
+            Ok(false)
+        }
+
+        /// 🧨 Reveal a hidden fuse and burn whoever held the potato when it
+        /// burned down
+        ///
+        /// The fuse length is drawn from the starter's secret and the game
+        /// seed. Only the starter can reveal, and only once the fuse has
+        /// expired and before the reveal window closes. A valid reveal
+        /// returns the starter's bond.
+        #[ink(message)]
+        pub fn reveal_fuse(&mut self, game_id: GameId, secret: Hash) -> Result<()> {
+            let mut game = self.active_game(game_id)?;
+            let mut fuse = game.fuse.clone().ok_or(HotPotatoError::NoHiddenFuse)?;
+            let bounds = game
+                .config
+                .fuse
+                .clone()
+                .ok_or(HotPotatoError::NoHiddenFuse)?;
+
+            let caller = self.env().caller();
+            if game.starter != caller {
+                return Err(HotPotatoError::OnlyStarter);
+            }
+
+            let now = self.now(game.deadline.unit);
+            if now > fuse.reveal_by {
+                return Err(HotPotatoError::RevealWindowClosed);
+            }
+            if fuse_commitment(&secret) != fuse.commitment {
+                return Err(HotPotatoError::InvalidFuseReveal);
+            }
+            let seed = game.beacon.seed.ok_or(HotPotatoError::GameNotActive)?;
+            let fuse_length = bounds.length_for(&secret, &seed);
+            let burned_at = fuse.lit_at.saturating_add(fuse_length);
+            if now <= burned_at {
+                return Err(HotPotatoError::FuseNotExpired);
+            }
+
+            let burned = self.holder_at(game_id, &fuse, burned_at)?;
+
+            let bond = core::mem::take(&mut fuse.bond);
+            fuse.revealed = Some(fuse_length);
+            game.fuse = Some(fuse);
+            self.credit(game_id, caller, bond);
+
+            // 🎯 EMIT EVENT - the reveal, the burn, then the end
+            self.env().emit_event(FuseRevealed {
+                game_id,
+                holder: burned,
+                fuse: fuse_length,
+            });
+            self.env().emit_event(PotatoBurned {
+                game_id,
+                potato: Some(0),
+                holder: burned,
+                block: self.env().block_number(),
+            });
+
+            self.update_stats(burned, |stats| stats.burns += 1);
+            game.eliminate(burned);
+            self.finish_game(game_id, game, caller, 0);
+            Ok(())
+        }
+
+        /// Helper function to apply `update` to the stats of `player`
+        fn update_stats(&mut self, player: H160, update: impl FnOnce(&mut PlayerStats)) {
+            let mut stats = self.stats.get(player).unwrap_or_default();
+            update(&mut stats);
+            self.stats.insert(player, &stats);
+        }
+
+        /// Helper function to update the streak and score of everyone
+        /// seated in a finished game and re-rank them
+        fn record_standings(&mut self, survivors: &[H160], out: &[H160], winners: &[H160]) {
+            for player in survivors {
+                let mut standing = self.standings.get(player).unwrap_or_default();
+                standing.streak += 1;
+                standing.score += SURVIVAL_POINTS;
+                if winners.contains(player) {
+                    standing.score += WIN_POINTS;
+                }
+                self.standings.insert(player, &standing);
+                self.rank_player(*player, &standing);
+            }
+            for player in out {
+                let mut standing = self.standings.get(player).unwrap_or_default();
+                standing.streak = 0;
+                self.standings.insert(player, &standing);
+                self.rank_player(*player, &standing);
+            }
+        }
+
+        /// Helper function to re-rate everyone seated in a finished game
+        ///
+        /// Survivors share first place; the eliminated follow, the last one
+        /// out placing best.
+        fn record_ratings(&mut self, survivors: &[H160], eliminated: &[H160]) {
+            let out = eliminated.len() as u32;
+            let (players, places): (Vec<H160>, Vec<u32>) = survivors
+                .iter()
+                .map(|player| (*player, 0))
+                .chain(
+                    eliminated
+                        .iter()
+                        .enumerate()
+                        .map(|(order, player)| (*player, out - order as u32)),
+                )
+                .unzip();
+            let ratings: Vec<u32> = players.iter().map(|p| self.get_rating(*p)).collect();
+
+            let updated = rating::updated(&ratings, &places, RATING_K, MIN_RATING);
+            for (player, rating) in players.iter().zip(updated) {
+                self.ratings.insert(player, &rating);
+            }
+        }
+
+        /// Helper function to move `player` to their place on every
+        /// leaderboard
+        fn rank_player(&mut self, player: H160, standing: &Standing) {
+            let wins = u64::from(self.stats.get(player).unwrap_or_default().wins);
+            self.rank(LeaderboardMetric::Wins, player, wins);
+            self.rank(
+                LeaderboardMetric::Streak,
+                player,
+                u64::from(standing.streak),
+            );
+            self.rank(LeaderboardMetric::Score, player, standing.score);
+        }
+
+        /// Helper function to place `player` on the `metric` leaderboard
+        ///
+        /// Ties keep whoever got there first ahead. Players whose value
+        /// drops to zero or below the last entry of a full board fall off.
+        fn rank(&mut self, metric: LeaderboardMetric, player: H160, value: u64) {
+            let size = self.leaderboard_size() as usize;
+            let mut board = self.leaderboards.get(metric).unwrap_or_default();
+            let current = LeaderboardEntry { player, value };
+            if board.contains(&current) {
+                return;
+            }
+            board.retain(|entry| entry.player != player);
+            if value > 0 {
+                let place = board
+                    .iter()
+                    .position(|entry| entry.value < value)
+                    .unwrap_or(board.len());
+                board.insert(place, current);
+            }
+            board.truncate(size);
+            self.leaderboards.insert(metric, &board);
+        }
+
+        /// Helper function to read the configured leaderboard size
+        fn leaderboard_size(&self) -> u32 {
+            self.leaderboard_size
+                .get()
+                .unwrap_or(DEFAULT_LEADERBOARD_SIZE)
+        }
+
+        /// Helper function to read the block the holder of `potato`
+        /// received it, on the game clock
+        fn hold_start(&self, game_id: GameId, potato: u32) -> u64 {
+            self.held_since.get((game_id, potato)).unwrap_or_default()
+        }
+
+        /// Helper function to start timing a hold of `potato` from the
+        /// current block
+        fn start_hold(&mut self, game_id: GameId, potato: u32) {
+            let now = self.now(DeadlineUnit::Blocks);
+            self.held_since.insert((game_id, potato), &now);
+        }
+
+        /// Helper function to credit `holder` with the blocks `potato` was
+        /// held so far and restart the hold; returns the blocks credited
+        fn end_hold(&mut self, game_id: GameId, potato: u32, holder: Option<H160>) -> u64 {
+            let held = self
+                .now(DeadlineUnit::Blocks)
+                .saturating_sub(self.hold_start(game_id, potato));
+            self.start_hold(game_id, potato);
+            if let Some(holder) = holder {
+                self.update_stats(holder, |stats| stats.blocks_held += held);
+            }
+            held
+        }
+
+        /// Helper function to read a potato of `game`
+        fn potato(&self, game_id: GameId, game: &Game, potato: u32) -> Option<Potato> {
+            if potato >= game.config.potatoes {
+                return None;
+            }
+            self.potatoes.get((game_id, potato))
+        }
+
+        /// Helper function to read the time left on a potato of `game`, in
+        /// the deadline's unit
+        fn remaining_on(&self, game_id: GameId, game: &Game, potato: u32) -> u64 {
+            self.potato(game_id, game, potato).map_or(0, |potato| {
+                game.remaining_of(&potato, self.now(game.deadline.unit))
+            })
+        }
+
+        /// Helper function to read every potato of `game`, by index
+        fn all_potatoes(&self, game_id: GameId, game: &Game) -> Vec<Potato> {
+            (0..game.config.potatoes)
+                .filter_map(|index| self.potato(game_id, game, index))
+                .collect()
+        }
+
+        /// Helper function to find the index of the potato `player` holds
+        fn potato_of(&self, game_id: GameId, game: &Game, player: H160) -> Option<u32> {
+            self.all_potatoes(game_id, game)
+                .iter()
+                .position(|potato| potato.holder == Some(player))
+                .map(|index| index as u32)
+        }
+
+        /// Helper function to hand every potato to consecutive seats from
+        /// `first_seat` with a fresh deadline
+        fn deal_potatoes(&mut self, game_id: GameId, game: &mut Game, first_seat: usize, now: u64) {
+            for index in 0..game.config.potatoes {
+                let seat = (first_seat + index as usize) % game.players.len();
+                let potato = Potato {
+                    holder: Some(game.players[seat]),
+                    last_passed_at: now,
+                    pass_count: 0,
+                };
+                self.potatoes.insert((game_id, index), &potato);
+                self.start_hold(game_id, index);
+            }
+        }
+
+        /// Helper function to append to the holder log of hidden fuse games
+        fn log_holder(&mut self, game_id: GameId, game: &mut Game, at: u64, holder: H160) {
+            let Some(fuse) = game.fuse.as_mut() else {
+                return;
+            };
+            self.holder_log
+                .insert((game_id, fuse.holder_changes), &HolderChange { at, holder });
+            fuse.holder_changes += 1;
+        }
+
+        /// Helper function to find who held the potato at `at` by binary
+        /// search over the holder log, which is ordered by time
+        fn holder_at(&self, game_id: GameId, fuse: &HiddenFuse, at: u64) -> Result<H160> {
+            // Find the first entry after `at`; the one before it is the holder
+            let (mut low, mut high) = (0, fuse.holder_changes);
+            while low < high {
+                let mid = low + (high - low) / 2;
+                let change = self
+                    .holder_log
+                    .get((game_id, mid))
+                    .ok_or(HotPotatoError::NoHolder)?;
+                if change.at <= at {
+                    low = mid + 1;
+                } else {
+                    high = mid;
+                }
+            }
+            let index = low.checked_sub(1).ok_or(HotPotatoError::NoHolder)?;
+            self.holder_log
+                .get((game_id, index))
+                .map(|change| change.holder)
+                .ok_or(HotPotatoError::NoHolder)
+        }
+
+        /// Helper function to pay the keeper bounty for a burn out of the pool
+        ///
+        /// Only reached when a burn actually happens, and every burn moves
+        /// the game on, so each expiry pays out at most once. The burned
+        /// holder cannot win back part of their forfeited stake this way.
+        fn reward_keeper(&mut self, game_id: GameId, game: &mut Game, keeper: H160, burned: H160) {
+            if keeper == burned {
+                return;
+            }
+
+            let reward = game.pool * Balance::from(game.config.keeper_reward_bps)
+                / Balance::from(BPS_DENOMINATOR);
+            if reward == 0 {
+                return;
+            }
+
+            game.pool -= reward;
+            self.credit(game_id, keeper, reward);
+
+            self.env().emit_event(KeeperRewarded {
+                game_id,
+                keeper,
+                amount: reward,
+            });
+        }
+
+        /// Helper function to hand the potato to the survivor seated after
+        /// the burned player and restart the clock with a full window
+        fn start_next_round(
+            &mut self,
+            game_id: GameId,
+            mut game: Game,
+            burned_seat: usize,
+            current_block: u32,
+        ) {
+            let holder = game.players[burned_seat % game.players.len()];
+
+            // Credit the holds the new deal cuts short
+            for index in 0..game.config.potatoes {
+                if let Some(potato) = self.potato(game_id, &game, index) {
+                    self.end_hold(game_id, index, potato.holder);
+                }
+            }
+            let now = self.now(game.deadline.unit);
+            self.deal_potatoes(game_id, &mut game, burned_seat, now);
+            game.round += 1;
+            game.round_started_block = current_block;
+            self.games.insert(game_id, &game);
+
+            self.env().emit_event(RoundStarted {
+                game_id,
+                holder,
+                round: game.round,
+                block: current_block,
+                survivors: game.players.len() as u32,
+            });
+        }
+
+        /// 🏆 End game manually (only by starter)
+        ///
+        /// Works on running games as well as on lobbies, which are cancelled.
+        #[ink(message)]
+        pub fn end_game(&mut self, game_id: GameId) -> Result<()> {
+            let game = self.game(game_id)?;
+            if game.status == GameStatus::Ended {
+                return Err(HotPotatoError::GameNotActive);
+            }
+
+            let caller = self.env().caller();
+            if game.starter != caller {
+                return Err(HotPotatoError::OnlyStarter);
+            }
+
+            let remaining = self.remaining_on(game_id, &game, 0);
+            self.finish_game(game_id, game, caller, remaining);
+            Ok(())
+        }
+
+        /// Helper function to mark a game as finished, record the last
+        /// player standing, credit the pool and emit `GameEnded`
+        ///
+        /// The pool is split evenly between the players still in the game:
+        /// the survivors of a running game, or everyone who joined a
+        /// cancelled lobby. Burned players have forfeited their stake.
+        fn finish_game(&mut self, game_id: GameId, mut game: Game, ended_by: H160, remaining: u64) {
+            let holder = self
+                .potato(game_id, &game, 0)
+                .and_then(|potato| potato.holder);
+            let was_running = game.is_active();
+            if was_running {
+                for index in 0..game.config.potatoes {
+                    if let Some(mut potato) = self.potato(game_id, &game, index) {
+                        self.end_hold(game_id, index, potato.holder);
+                        potato.holder = None;
+                        self.potatoes.insert((game_id, index), &potato);
+                    }
+                    self.held_since.remove((game_id, index));
+                }
+                self.started_at.remove(game_id);
+                if game.players.len() == 1 {
+                    game.winner = game.players.first().copied();
+                }
+            }
+            // Team games are won by every member of the least burned teams,
+            // and the other teams count as knocked out
+            let (survivors, out, winners) = if was_running && game.config.mode == GameMode::Teams {
+                let (winners, losers) = self.team_result(game_id, &game);
+                (winners.clone(), losers, winners)
+            } else {
+                let winners = game.winner.into_iter().collect();
+                (game.players.clone(), game.eliminated.clone(), winners)
+            };
+            for winner in &winners {
+                self.update_stats(*winner, |stats| stats.wins += 1);
+            }
+            if was_running {
+                self.record_standings(&survivors, &out, &winners);
+                self.record_ratings(&survivors, &out);
+            }
+
+            // A fuse that was never revealed forfeits the starter's bond
+            if let Some(fuse) = game.fuse.as_mut() {
+                let bond = core::mem::take(&mut fuse.bond);
+                if bond > 0 {
+                    game.pool += bond;
+                    self.env().emit_event(FuseSlashed {
+                        game_id,
+                        starter: game.starter,
+                        bond,
+                    });
+                }
+            }
+
+            let pool = core::mem::take(&mut game.pool);
+            game.status = GameStatus::Ended;
+            self.games.insert(game_id, &game);
+            self.untrack_active(game_id);
+
+            let payees = if game.config.mode == GameMode::Teams && !winners.is_empty() {
+                &winners
+            } else {
+                &game.players
+            };
+            self.split_pool(game_id, payees, pool);
+
+            // 🎯 EMIT EVENT
+            self.env().emit_event(GameEnded {
+                game_id,
+                ended_by,
+                holder,
+                block: self.env().block_number(),
+                remaining,
+                winner: game.winner,
+            });
+        }
+
+        /// Helper function to share `pool` evenly between `players`; the
+        /// first player also receives any indivisible remainder
+        fn split_pool(&mut self, game_id: GameId, players: &[H160], pool: Balance) {
+            let Some(first) = players.first() else {
+                return;
+            };
+            let share = pool / players.len() as Balance;
+            let remainder = pool - share * players.len() as Balance;
+
+            self.credit(game_id, *first, share + remainder);
+            for player in &players[1..] {
+                self.credit(game_id, *player, share);
+            }
+        }
+
+        /// Helper function to add `amount` to what `player` can withdraw
+        ///
+        /// Game resolution never transfers funds itself, so a recipient that
+        /// cannot receive them only ever affects its own withdrawal.
+        fn credit(&mut self, game_id: GameId, player: H160, amount: Balance) {
+            if amount == 0 {
+                return;
+            }
+
+            let balance = self.claimable.get(player).unwrap_or(0);
+            self.claimable.insert(player, &(balance + amount));
+
+            self.env().emit_event(WinningsCredited {
+                game_id,
+                player,
+                amount,
+            });
+        }
+
+        /// 💰 Transfer the caller's whole claimable balance to them
+        #[ink(message)]
+        pub fn withdraw(&mut self) -> Result<Balance> {
+            let caller = self.env().caller();
+            let amount = self.claimable.take(caller).unwrap_or(0);
+            if amount == 0 {
+                return Err(HotPotatoError::NothingToWithdraw);
+            }
+
+            if self.env().transfer(caller, U256::from(amount)).is_err() {
+                self.claimable.insert(caller, &amount);
+                return Err(HotPotatoError::PayoutFailed);
+            }
+
+            self.env().emit_event(Withdrawn {
+                player: caller,
+                amount,
+            });
+
+            Ok(amount)
+        }
+
+        /// 👑 Propose `new_owner` as the next owner
+        ///
+        /// Ownership only moves once `new_owner` calls `accept_ownership`;
+        /// proposing again replaces the pending owner.
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: H160) -> Result<()> {
+            self.ensure_owner()?;
+            Self::ensure_valid_recipient(&new_owner)?;
+
+            self.pending_owner.set(&Some(new_owner));
+            self.env().emit_event(OwnershipTransferStarted {
+                owner: self.owner.get_or_default(),
+                pending_owner: new_owner,
+            });
+            Ok(())
+        }
+
+        /// 👑 Accept ownership proposed by the current owner
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            if self.pending_owner.get_or_default() != Some(caller) {
+                return Err(HotPotatoError::NotPendingOwner);
+            }
+
+            let previous_owner = self.owner.get_or_default();
+            self.owner.set(&caller);
+            self.pending_owner.set(&None);
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: caller,
+            });
+            Ok(())
+        }
+
+        /// 🛠️ Set the deadline applied to games created from now on
+        #[ink(message)]
+        pub fn set_default_deadline(&mut self, deadline: Deadline) -> Result<()> {
+            self.ensure_owner()?;
+            deadline.validate()?;
+            if !self.time_limits.get().unwrap_or_default().fits(&deadline) {
+                return Err(HotPotatoError::InvalidDeadline);
+            }
+
+            self.deadline.set(&deadline);
+            self.env().emit_event(ConfigChanged {
+                owner: self.owner.get_or_default(),
+                change: ConfigChange::DefaultDeadline(deadline),
+            });
+            Ok(())
+        }
+
+        /// 🛠️ Set the player limits new games must stay within
+        #[ink(message)]
+        pub fn set_player_limits(&mut self, min: u32, max: u32) -> Result<()> {
+            let settings = Settings {
+                min_players: min,
+                max_players: max,
+                ..self.settings.get_or_default()
+            };
+            self.update_settings(settings, ConfigChange::PlayerLimits { min, max })
+        }
+
+        /// 🛠️ Set the entry fee bounds new games must stay within
+        #[ink(message)]
+        pub fn set_entry_fee_limits(&mut self, min: Balance, max: Balance) -> Result<()> {
+            let settings = Settings {
+                min_entry_fee: min,
+                max_entry_fee: max,
+                ..self.settings.get_or_default()
+            };
+            self.update_settings(settings, ConfigChange::EntryFeeLimits { min, max })
+        }
+
+        /// 🛠️ Set the largest keeper reward new games may offer
+        #[ink(message)]
+        pub fn set_max_keeper_reward(&mut self, bps: u16) -> Result<()> {
+            let settings = Settings {
+                max_keeper_reward_bps: bps,
+                ..self.settings.get_or_default()
+            };
+            self.update_settings(settings, ConfigChange::MaxKeeperReward(bps))
+        }
+
+        /// 🛠️ Allow or forbid new games of `mode`
+        ///
+        /// Games that already exist keep running either way.
+        #[ink(message)]
+        pub fn set_mode_enabled(&mut self, mode: GameMode, enabled: bool) -> Result<()> {
+            let mut settings = self.settings.get_or_default();
+            match mode {
+                GameMode::Classic => settings.classic_enabled = enabled,
+                GameMode::Elimination => settings.elimination_enabled = enabled,
+                GameMode::Teams => settings.teams_enabled = enabled,
+            }
+            self.update_settings(settings, ConfigChange::ModeEnabled { mode, enabled })
+        }
+
+        /// 🛠️ Set how many entries each leaderboard keeps
+        ///
+        /// Shrinking drops the lowest entries right away; growing only fills
+        /// up as games resolve.
+        #[ink(message)]
+        pub fn set_leaderboard_size(&mut self, size: u32) -> Result<()> {
+            self.ensure_owner()?;
+            if size == 0 || size > MAX_LEADERBOARD_SIZE {
+                return Err(HotPotatoError::InvalidLeaderboardSize);
+            }
+
+            self.leaderboard_size.set(&size);
+            for metric in [
+                LeaderboardMetric::Wins,
+                LeaderboardMetric::Streak,
+                LeaderboardMetric::Score,
+            ] {
+                if let Some(mut board) = self.leaderboards.get(metric) {
+                    board.truncate(size as usize);
+                    self.leaderboards.insert(metric, &board);
+                }
+            }
+            self.env().emit_event(ConfigChanged {
+                owner: self.owner.get_or_default(),
+                change: ConfigChange::LeaderboardSize(size),
+            });
+            Ok(())
+        }
+
+        /// 🛠️ Set the rules every pass must follow, in running games too
+        #[ink(message)]
+        pub fn set_pass_rules(&mut self, rules: PassRules) -> Result<()> {
+            self.ensure_owner()?;
+            rules.validate()?;
+
+            self.pass_rules.set(&rules);
+            self.env().emit_event(ConfigChanged {
+                owner: self.owner.get_or_default(),
+                change: ConfigChange::PassRules(rules),
+            });
+            Ok(())
+        }
+
+        /// ⏳ Set the minimum hold, the game-length cap and the tie-break for
+        /// capped games (owner only)
+        #[ink(message)]
+        pub fn set_time_limits(&mut self, limits: TimeLimits) -> Result<()> {
+            self.ensure_owner()?;
+            limits.validate(&self.default_deadline())?;
+
+            self.time_limits.set(&limits);
+            self.env().emit_event(ConfigChanged {
+                owner: self.owner.get_or_default(),
+                change: ConfigChange::TimeLimits(limits),
+            });
+            Ok(())
+        }
+
+        /// 🛠️ Appoint the account that may pause play besides the owner, or
+        /// remove it with `None`
+        #[ink(message)]
+        pub fn set_guardian(&mut self, guardian: Option<H160>) -> Result<()> {
+            self.ensure_owner()?;
+            if let Some(guardian) = &guardian {
+                Self::ensure_valid_recipient(guardian)?;
+            }
+
+            self.guardian.set(&guardian);
+            self.env().emit_event(ConfigChanged {
+                owner: self.owner.get_or_default(),
+                change: ConfigChange::Guardian(guardian),
+            });
+            Ok(())
+        }
+
+        /// 🧬 Replace the contract code, keeping storage and balance
+        ///
+        /// New code must still decode the current root layout; anything it
+        /// adds belongs in new `Mapping` or `Lazy` fields, filled in by
+        /// `migrate` right after the upgrade.
+        #[ink(message)]
+        pub fn upgrade(&mut self, code_hash: H256) -> Result<()> {
+            self.ensure_owner()?;
+            self.env()
+                .set_code_hash(&code_hash)
+                .map_err(|_| HotPotatoError::UpgradeFailed)?;
+
+            self.env().emit_event(Upgraded { code_hash });
+            Ok(())
+        }
+
+        /// 🧳 Bring storage written by older code up to `STORAGE_VERSION`
+        /// and return the new version
+        ///
+        /// Version 0 is the first version's flat single game, which had no
+        /// owner: only `LEGACY_OWNER` may migrate it, and becomes the owner.
+        #[ink(message)]
+        pub fn migrate(&mut self) -> Result<u32> {
+            let from = self.storage_version.get_or_default();
+            if from != 0 {
+                self.ensure_owner()?;
+            } else if self.env().caller() != LEGACY_OWNER {
+                return Err(HotPotatoError::OnlyOwner);
+            }
+            if from >= STORAGE_VERSION {
+                return Err(HotPotatoError::AlreadyMigrated);
+            }
+
+            // Every version bump adds a step here converting the layout it
+            // replaces
+            if from == 0 {
+                self.migrate_legacy_game();
+            }
+            self.storage_version.set(&STORAGE_VERSION);
+
+            self.env().emit_event(Migrated {
+                from,
+                to: STORAGE_VERSION,
+            });
+            Ok(STORAGE_VERSION)
+        }
+
+        /// Helper function to turn the first version's flat game into a
+        /// running `Game` played by its holder and starter
+        fn migrate_legacy_game(&mut self) {
+            let legacy = core::mem::take(&mut self.legacy);
+            let starter = legacy.game_starter.as_ref().map(legacy_address);
+            self.owner.set(&LEGACY_OWNER);
+            let deadline =
+                Deadline::constant(u64::from(legacy.deadline_blocks), DeadlineUnit::Blocks);
+            self.deadline.set(&deadline);
+
+            let holder = legacy.current_holder.as_ref().map(legacy_address);
+            let (true, Some(holder), Some(starter)) = (legacy.active, holder, starter) else {
+                return;
+            };
+            let mut players = Vec::from([holder]);
+            if starter != holder {
+                players.push(starter);
+            }
+            let last_passed_at = u64::from(legacy.last_passed_block);
+            let game = Game {
+                starter,
+                deadline,
+                status: GameStatus::Active,
+                players,
+                eliminated: Vec::new(),
+                config: GameConfig::default(),
+                round: 1,
+                round_started_block: legacy.last_passed_block,
+                winner: None,
+                pool: 0,
+                fuse: None,
+                beacon: Beacon::default(),
+            };
+            let game_id = self.next_game_id.get_or_default();
+            self.games.insert(game_id, &game);
+            self.next_game_id.set(&(game_id + 1));
+            self.track_active(game_id);
+            self.potatoes.insert(
+                (game_id, 0),
+                &Potato {
+                    holder: Some(holder),
+                    last_passed_at,
+                    pass_count: 0,
+                },
+            );
+            self.held_since.insert((game_id, 0), &last_passed_at);
+            self.started_at.insert(game_id, &last_passed_at);
+        }
+
+        /// ⏸️ Stop play and freeze every game clock
+        ///
+        /// Creating, joining, closing lobbies, revealing entropy, starting
+        /// and passing are rejected until `unpause`. Expired potatoes can
+        /// still be burned, games ended and winnings withdrawn.
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<()> {
+            self.ensure_guardian()?;
+            self.ensure_not_paused()?;
+
+            self.paused_at.set(&Some(self.clock()));
+            self.env().emit_event(Paused {
+                by: self.env().caller(),
+                block: self.env().block_number(),
+            });
+            Ok(())
+        }
+
+        /// ▶️ Resume play; game clocks carry on from where they stopped
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<()> {
+            self.ensure_guardian()?;
+            let paused_at = self
+                .paused_at
+                .get_or_default()
+                .ok_or(HotPotatoError::NotPaused)?;
+            self.paused_at.set(&None);
+
+            let clock = self.clock();
+            let paused_for = ClockReading {
+                blocks: clock.blocks.saturating_sub(paused_at.blocks),
+                millis: clock.millis.saturating_sub(paused_at.millis),
+            };
+            let mut paused_total = self.paused_total.get_or_default();
+            paused_total.blocks += paused_for.blocks;
+            paused_total.millis += paused_for.millis;
+            self.paused_total.set(&paused_total);
+            self.env().emit_event(Unpaused {
+                by: self.env().caller(),
+                paused_for,
+            });
+            Ok(())
+        }
+
+        /// 🧹 Delete up to `limit` of the oldest pass records of an ended
+        /// game and return how many were removed
+        ///
+        /// Anyone may prune, at most `MAX_PAGE_SIZE` records per call. The
+        /// history of a running game stays complete.
+        #[ink(message)]
+        pub fn prune_pass_history(&mut self, game_id: GameId, limit: u32) -> Result<u32> {
+            let game = self.game(game_id)?;
+            if game.status != GameStatus::Ended {
+                return Err(HotPotatoError::GameNotEnded);
+            }
+
+            let mut log = self.pass_logs.get(game_id).unwrap_or_default();
+            let end = log
+                .pruned
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(log.len);
+            for index in log.pruned..end {
+                self.pass_history.remove((game_id, index));
+            }
+            let removed = end - log.pruned;
+            log.pruned = end;
+            self.pass_logs.insert(game_id, &log);
+            Ok(removed)
+        }
+
+        /// Helper function to append a game to the active list
+        fn track_active(&mut self, game_id: GameId) {
+            let slot = self.active_game_count.get_or_default();
+            self.active_games.insert(slot, &game_id);
+            self.active_game_slots.insert(game_id, &slot);
+            self.active_game_count.set(&(slot + 1));
+        }
+
+        /// Helper function to drop a game from the active list by moving the
+        /// last entry into its slot, keeping the list dense
+        fn untrack_active(&mut self, game_id: GameId) {
+            let Some(slot) = self.active_game_slots.take(game_id) else {
+                return;
+            };
+            let last = self.active_game_count.get_or_default() - 1;
+            if slot != last {
+                if let Some(moved) = self.active_games.get(last) {
+                    self.active_games.insert(slot, &moved);
+                    self.active_game_slots.insert(moved, &slot);
+                }
+            }
+            self.active_games.remove(last);
+            self.active_game_count.set(&last);
+        }
+
+        /// 🔑 Map a 32-byte SS58 `AccountId` to the `H160` address it signs as
+        ///
+        /// This is the only place accounts are converted; every other message
+        /// works on the native caller address.
+        #[ink(message)]
+        pub fn address_of(&self, account: AccountId) -> H160 {
+            account_to_address(&account)
+        }
+
+        /// 📖 Query functions (no events needed for reads)
+
+        #[ink(message)]
+        pub fn get_game(&self, game_id: GameId) -> Option<Game> {
+            self.games.get(game_id)
+        }
+
+        /// Current holder of a potato
+        #[ink(message)]
+        pub fn get_holder(&self, game_id: GameId, potato: u32) -> Option<H160> {
+            let game = self.games.get(game_id)?;
+            self.potato(game_id, &game, potato)?.holder
+        }
+
+        /// Potatoes a game is played with
+        #[ink(message)]
+        pub fn get_potato_count(&self, game_id: GameId) -> u32 {
+            self.games
+                .get(game_id)
+                .map_or(0, |game| game.config.potatoes)
+        }
+
+        /// Every potato of a game with its holder and last pass, by index
+        #[ink(message)]
+        pub fn get_potatoes(&self, game_id: GameId) -> Vec<Potato> {
+            self.games
+                .get(game_id)
+                .map_or(Vec::new(), |game| self.all_potatoes(game_id, &game))
+        }
+
+        /// Session key `delegate` holds for `game_id`, expired or not
+        #[ink(message)]
+        pub fn get_session_key(&self, game_id: GameId, delegate: H160) -> Option<SessionKey> {
+            self.session_keys.get((delegate, game_id))
+        }
+
+        #[ink(message)]
+        pub fn is_active(&self, game_id: GameId) -> bool {
+            self.games.get(game_id).is_some_and(|game| game.is_active())
+        }
+
+        #[ink(message)]
+        pub fn get_status(&self, game_id: GameId) -> Option<GameStatus> {
+            self.games.get(game_id).map(|game| game.status)
+        }
+
+        /// Players still in the game, in join order
+        #[ink(message)]
+        pub fn get_players(&self, game_id: GameId) -> Vec<H160> {
+            self.games
+                .get(game_id)
+                .map(|game| game.players)
+                .unwrap_or_default()
+        }
+
+        /// Players knocked out so far, first elimination first
+        #[ink(message)]
+        pub fn get_elimination_order(&self, game_id: GameId) -> Vec<H160> {
+            self.games
+                .get(game_id)
+                .map(|game| game.eliminated)
+                .unwrap_or_default()
+        }
+
+        /// Entry fees currently held for a game
+        #[ink(message)]
+        pub fn get_pool(&self, game_id: GameId) -> Balance {
+            self.games.get(game_id).map_or(0, |game| game.pool)
+        }
+
+        /// Winnings and refunds `account` can withdraw
+        #[ink(message)]
+        pub fn claimable_of(&self, account: H160) -> Balance {
+            self.claimable.get(account).unwrap_or(0)
+        }
+
+        /// Last player standing of a finished game
+        #[ink(message)]
+        pub fn get_winner(&self, game_id: GameId) -> Option<H160> {
+            self.games.get(game_id).and_then(|game| game.winner)
+        }
+
+        /// Current round of a game, 0 while it is still in the lobby
+        #[ink(message)]
+        pub fn get_round(&self, game_id: GameId) -> u32 {
+            self.games.get(game_id).map_or(0, |game| game.round)
+        }
+
+        #[ink(message)]
+        pub fn is_registered(&self, game_id: GameId, player: H160) -> bool {
+            self.games
+                .get(game_id)
+                .is_some_and(|game| game.is_player(&player) || game.eliminated.contains(&player))
+        }
+
+        /// Account allowed to change the contract settings
+        #[ink(message)]
+        pub fn get_owner(&self) -> H160 {
+            self.owner.get_or_default()
+        }
+
+        /// Account proposed as the next owner, if any
+        #[ink(message)]
+        pub fn get_pending_owner(&self) -> Option<H160> {
+            self.pending_owner.get_or_default()
+        }
+
+        /// Bounds new games are validated against
+        #[ink(message)]
+        pub fn get_settings(&self) -> Settings {
+            self.settings.get_or_default()
+        }
+
+        /// Account that may pause play besides the owner
+        #[ink(message)]
+        pub fn get_guardian(&self) -> Option<H160> {
+            self.guardian.get_or_default()
+        }
+
+        /// Whether play is paused
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused_at.get_or_default().is_some()
+        }
+
+        /// Lifetime record of `player`, all zero if they never played
+        #[ink(message)]
+        pub fn get_player_stats(&self, player: H160) -> PlayerStats {
+            self.stats.get(player).unwrap_or_default()
+        }
+
+        /// Streak and score of `player`, all zero if they never finished a
+        /// game
+        #[ink(message)]
+        pub fn get_standing(&self, player: H160) -> Standing {
+            self.standings.get(player).unwrap_or_default()
+        }
+
+        /// Up to `limit` best players on `metric`, best first
+        #[ink(message)]
+        pub fn get_leaderboard(
+            &self,
+            metric: LeaderboardMetric,
+            limit: u32,
+        ) -> Vec<LeaderboardEntry> {
+            let mut board = self.leaderboards.get(metric).unwrap_or_default();
+            board.truncate(limit as usize);
+            board
+        }
+
+        /// Entries each leaderboard keeps
+        #[ink(message)]
+        pub fn get_leaderboard_size(&self) -> u32 {
+            self.leaderboard_size()
+        }
+
+        /// Up to `limit` pass records of a game starting at pass `start`,
+        /// oldest first; pruned records are skipped
+        #[ink(message)]
+        pub fn get_pass_history(&self, game_id: GameId, start: u32, limit: u32) -> Vec<PassRecord> {
+            let log = self.pass_logs.get(game_id).unwrap_or_default();
+            let start = start.max(log.pruned);
+            let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(log.len);
+            (start..end)
+                .filter_map(|index| self.pass_history.get((game_id, index)))
+                .collect()
+        }
+
+        /// Which pass records of a game are still stored
+        #[ink(message)]
+        pub fn get_pass_log(&self, game_id: GameId) -> PassLog {
+            self.pass_logs.get(game_id).unwrap_or_default()
+        }
+
+        /// Rules every pass must follow
+        #[ink(message)]
+        pub fn get_pass_rules(&self) -> PassRules {
+            self.pass_rules.get().unwrap_or_default()
+        }
+
+        /// Minimum hold, game-length cap and tie-break in force
+        #[ink(message)]
+        pub fn get_time_limits(&self) -> TimeLimits {
+            self.time_limits.get().unwrap_or_default()
+        }
+
+        /// Blocks a running game has gone on, if it is held to the
+        /// game-length cap
+        #[ink(message)]
+        pub fn get_game_length(&self, game_id: GameId) -> Option<u64> {
+            self.game_length(game_id)
+        }
+
+        /// Team setup of a team game
+        #[ink(message)]
+        pub fn get_team_config(&self, game_id: GameId) -> Option<TeamConfig> {
+            self.team_configs.get(game_id)
+        }
+
+        /// Team `player` plays for in a team game
+        #[ink(message)]
+        pub fn get_team(&self, game_id: GameId, player: H160) -> Option<u32> {
+            self.teams.get((game_id, player))
+        }
+
+        /// Teams of a team game, fewest burns first; empty for other games
+        #[ink(message)]
+        pub fn get_team_standings(&self, game_id: GameId) -> Vec<TeamStanding> {
+            let (Some(game), Some(burns)) = (self.games.get(game_id), self.team_burns.get(game_id))
+            else {
+                return Vec::new();
+            };
+            let mut standings: Vec<TeamStanding> = burns
+                .iter()
+                .enumerate()
+                .map(|(team, burns)| TeamStanding {
+                    team: team as u32,
+                    members: game
+                        .players
+                        .iter()
+                        .copied()
+                        .filter(|player| self.teams.get((game_id, *player)) == Some(team as u32))
+                        .collect(),
+                    burns: *burns,
+                })
+                .collect();
+            standings.sort_by_key(|standing| standing.burns);
+            standings
+        }
+
+        /// Elo rating of `player`, `INITIAL_RATING` until they finish a game
+        #[ink(message)]
+        pub fn get_rating(&self, player: H160) -> u32 {
+            self.ratings.get(player).unwrap_or(INITIAL_RATING)
+        }
+
+        /// Layout version the storage was last written in
+        #[ink(message)]
+        pub fn get_storage_version(&self) -> u32 {
+            self.storage_version.get_or_default()
+        }
+
+        /// Deadline applied to new games that do not pick their own
+        #[ink(message)]
+        pub fn get_default_deadline(&self) -> Deadline {
+            self.default_deadline()
+        }
+
+        /// Deadline a game was created with
+        #[ink(message)]
+        pub fn get_deadline(&self, game_id: GameId) -> Option<Deadline> {
+            self.games.get(game_id).map(|game| game.deadline)
+        }
+
+        /// When a potato was last passed, in the game's deadline unit
+        #[ink(message)]
+        pub fn get_last_passed_at(&self, game_id: GameId, potato: u32) -> u64 {
+            self.games
+                .get(game_id)
+                .and_then(|game| self.potato(game_id, &game, potato))
+                .map_or(0, |potato| potato.last_passed_at)
+        }
+
+        #[ink(message)]
+        pub fn get_game_starter(&self, game_id: GameId) -> Option<H160> {
+            self.games.get(game_id).map(|game| game.starter)
+        }
+
+        /// Time the current holder of a potato was given to pass it, in the
+        /// game's deadline unit; 0 unless the game is running
+        #[ink(message)]
+        pub fn get_current_window(&self, game_id: GameId, potato: u32) -> u64 {
+            let Some(game) = self.games.get(game_id).filter(|game| game.is_active()) else {
+                return 0;
+            };
+            self.potato(game_id, &game, potato)
+                .map_or(0, |potato| game.window_of(&potato))
+        }
+
+        /// Time the holder of a potato has left, in the game's deadline unit
+        #[ink(message)]
+        pub fn get_remaining_blocks(&self, game_id: GameId, potato: u32) -> u64 {
+            self.games
+                .get(game_id)
+                .map_or(0, |game| self.remaining_on(game_id, &game, potato))
+        }
+
+        /// Id the next created game will receive
+        #[ink(message)]
+        pub fn get_next_game_id(&self) -> GameId {
+            self.next_game_id.get_or_default()
+        }
+
+        #[ink(message)]
+        pub fn get_active_game_count(&self) -> u32 {
+            self.active_game_count.get_or_default()
+        }
+
+        /// Page through ids of games in the lobby or running; `limit` is
+        /// capped at `MAX_PAGE_SIZE`
+        #[ink(message)]
+        pub fn get_active_games(&self, start: u32, limit: u32) -> Vec<GameId> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.active_game_count.get_or_default());
+            (start..end)
+                .filter_map(|slot| self.active_games.get(slot))
+                .collect()
+        }
+    }
+
+    /// Same derivation as pallet-revive's `AccountId32Mapper`: accounts that
+    /// were derived from an Ethereum address (suffix of twelve `0xEE` bytes)
+    /// map back to their prefix, all others to the last 20 bytes of their
+    /// Keccak-256 hash.
+    fn account_to_address(account: &AccountId) -> H160 {
+        let bytes: &[u8; 32] = account.as_ref();
+        if bytes[20..].iter().all(|byte| *byte == 0xEE) {
+            return H160::from_slice(&bytes[..20]);
+        }
+        let mut hash = <Keccak256 as HashOutput>::Type::default();
+        ink::env::hash_bytes::<Keccak256>(bytes, &mut hash);
+        H160::from_slice(&hash[12..])
+    }
+
+    /// Map an account stored by the first version back to its address;
+    /// that version padded caller addresses with zeros
+    fn legacy_address(account: &AccountId) -> H160 {
+        let bytes: &[u8; 32] = account.as_ref();
+        if bytes[20..].iter().all(|byte| *byte == 0) {
+            return H160::from_slice(&bytes[..20]);
+        }
+        account_to_address(account)
+    }
+
+    /// Keccak-256 hash of the SCALE encoding of `value`
+    fn keccak_encoded<T: scale::Encode>(value: &T) -> Hash {
+        let mut hash = <Keccak256 as HashOutput>::Type::default();
+        ink::env::hash_encoded::<Keccak256, _>(value, &mut hash);
+        Hash::from(hash)
+    }
+
+    /// 🧨 Commitment a starter passes to `close_lobby` for a hidden fuse: the
+    /// Keccak-256 hash of the SCALE-encoded secret
+    pub fn fuse_commitment(secret: &Hash) -> Hash {
+        keccak_encoded(secret)
+    }
+
+    /// 🎲 Commitment a player passes to `join_game`: the Keccak-256 hash of
+    /// the SCALE-encoded `(secret, player)` pair, so nobody can replay
+    /// another player's commitment
+    pub fn entropy_commitment(secret: &Hash, player: H160) -> Hash {
+        keccak_encoded(&(secret, player))
+    }
+
+    /// XOR a revealed secret into the beacon
+    fn mix_entropy(mix: &Hash, secret: &Hash) -> Hash {
+        let mut bytes = [0u8; 32];
+        for (byte, (a, b)) in bytes
+            .iter_mut()
+            .zip(mix.as_ref().iter().zip(secret.as_ref()))
+        {
+            *byte = a ^ b;
+        }
+        Hash::from(bytes)
+    }
+
+    /// Seed of a game: the mixed entropy hashed together with the game id,
+    /// so equal reveals in two games still draw differently
+    fn beacon_seed(game_id: GameId, mix: &Hash) -> Hash {
+        keccak_encoded(&(game_id, mix))
+    }
+
+    /// First eight bytes of `hash` as a little-endian number
+    fn hash_word(hash: &Hash) -> u64 {
+        let mut word = [0u8; 8];
+        word.copy_from_slice(&hash.as_ref()[..8]);
+        u64::from_le_bytes(word)
+    }
+
+    /// Seat in `0..seats` drawn from `seed`
+    fn seat_for(seed: &Hash, seats: usize) -> usize {
+        (hash_word(seed) % seats as u64) as usize
+    }
+
+    /// ⭐ Elo ratings for games of any size, in integer arithmetic
+    ///
+    /// A finished game counts as a round robin: every player is scored
+    /// against every other by placement (1 for finishing ahead, ½ for a tie,
+    /// 0 for finishing behind) and moves by `k` times their average surprise.
+    mod rating {
+        use core::cmp::Ordering;
+        use ink::prelude::vec::Vec;
+
+        /// Fixed-point scale of scores and expected scores
+        pub const SCALE: i64 = 1_000_000;
+
+        /// Rating gap beyond which the favourite's expected score stops
+        /// growing
+        const MAX_GAP: u32 = 800;
+
+        /// 10^(i/16) times `SCALE`, the Elo curve in 25 point steps
+        const POW10_SIXTEENTHS: [i64; 17] = [
+            1_000_000, 1_154_782, 1_333_521, 1_539_927, 1_778_279, 2_053_525, 2_371_374, 2_738_420,
+            3_162_278, 3_651_741, 4_216_965, 4_869_675, 5_623_413, 6_493_816, 7_498_942, 8_659_643,
+            10_000_000,
+        ];
+
+        /// 10^(gap/400) times `SCALE` for `gap <= MAX_GAP`, interpolated
+        /// linearly between 25 point steps
+        fn pow10(gap: u32) -> i64 {
+            let whole = 10i64.pow(gap / 400);
+            let rest = gap % 400;
+            let step = (rest / 25) as usize;
+            let offset = i64::from(rest % 25);
+            let low = POW10_SIXTEENTHS[step];
+            let high = POW10_SIXTEENTHS[step + 1];
+            whole * (low + (high - low) * offset / 25)
+        }
+
+        /// Expected score of `rating` against `opponent`, times `SCALE`
+        pub fn expected(rating: u32, opponent: u32) -> i64 {
+            let odds = pow10(rating.abs_diff(opponent).min(MAX_GAP));
+            let favourite = odds * SCALE / (odds + SCALE);
+            if rating >= opponent {
+                favourite
+            } else {
+                SCALE - favourite
+            }
+        }
+
+        /// New ratings after a game in which `ratings[i]` finished in
+        /// `places[i]`, lower places being better; nobody drops below `floor`
+        pub fn updated(ratings: &[u32], places: &[u32], k: u32, floor: u32) -> Vec<u32> {
+            let opponents = ratings.len().saturating_sub(1) as i64;
+            if opponents == 0 {
+                return ratings.to_vec();
+            }
+
+            let seats = || ratings.iter().zip(places).enumerate();
+            seats()
+                .map(|(seat, (&rating, place))| {
+                    let surprise: i64 = seats()
+                        .filter(|(other_seat, _)| *other_seat != seat)
+                        .map(|(_, (&other, other_place))| {
+                            let score = match place.cmp(other_place) {
+                                Ordering::Less => SCALE,
+                                Ordering::Equal => SCALE / 2,
+                                Ordering::Greater => 0,
+                            };
+                            score - expected(rating, other)
+                        })
+                        .sum();
+                    let change = i64::from(k) * surprise / (opponents * SCALE);
+                    (i64::from(rating) + change).max(i64::from(floor)) as u32
+                })
+                .collect()
+        }
+    }
+
+    /// 🧪 Unit Tests - Testing Events and Game Logic
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn recorded_events() -> Vec<ink::env::test::EmittedEvent> {
+            ink::env::test::recorded_events()
+        }
+
+        fn decode_event<T: scale::Decode>(event: &ink::env::test::EmittedEvent) -> T {
+            T::decode(&mut &event.data[..]).expect("encountered invalid event data")
+        }
+
+        fn advance_blocks(blocks: u32) {
+            for _ in 0..blocks {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+        }
+
+        /// Alice opens a lobby and every account in `players` joins it
+        fn open_lobby(hotpotato: &mut Hotpotato, players: &[H160]) -> GameId {
+            open_lobby_with(hotpotato, GameConfig::default(), players)
+        }
+
+        /// Alice opens a lobby with `config` and every account in `players`
+        /// joins it
+        fn open_lobby_with(
+            hotpotato: &mut Hotpotato,
+            config: GameConfig,
+            players: &[H160],
+        ) -> GameId {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let game_id = hotpotato.create_game(config).unwrap();
+            for player in players {
+                ink::env::test::set_caller(*player);
+                hotpotato
+                    .join_game(game_id, commitment_for(game_id, players, *player))
+                    .unwrap();
+            }
+            ink::env::test::set_caller(accounts.alice);
+            game_id
+        }
+
+        /// Secrets `players` commit to in `game_id`, in join order; the last
+        /// one is ground so that the beacon hands the potato to the first
+        fn entropy_secrets(game_id: GameId, players: &[H160]) -> Vec<Hash> {
+            let mut secrets: Vec<Hash> = (0..players.len())
+                .map(|seat| Hash::from([seat as u8 + 1; 32]))
+                .collect();
+            let Some(last) = players.len().checked_sub(1) else {
+                return secrets;
+            };
+            for nonce in 0u32.. {
+                let mut bytes = [0xAB; 32];
+                bytes[..4].copy_from_slice(&nonce.to_le_bytes());
+                secrets[last] = Hash::from(bytes);
+                let mix = secrets
+                    .iter()
+                    .fold(Hash::default(), |mix, secret| mix_entropy(&mix, secret));
+                if seat_for(&beacon_seed(game_id, &mix), players.len()) == 0 {
+                    break;
+                }
+            }
+            secrets
+        }
+
+        /// Commitment `player` joins with, out of the seats in `players`
+        fn commitment_for(game_id: GameId, players: &[H160], player: H160) -> Hash {
+            let seat = players.iter().position(|p| *p == player).unwrap();
+            entropy_commitment(&entropy_secrets(game_id, players)[seat], player)
+        }
+
+        /// Commitment for joins whose reveal the test never needs
+        fn any_commitment(player: H160) -> Hash {
+            entropy_commitment(&Hash::default(), player)
+        }
+
+        /// Every player of `game_id` reveals their secret from
+        /// `entropy_secrets`
+        fn reveal_all(hotpotato: &mut Hotpotato, game_id: GameId) {
+            let accounts = ink::env::test::default_accounts();
+            let players = hotpotato.get_players(game_id);
+            for (player, secret) in players.iter().zip(entropy_secrets(game_id, &players)) {
+                ink::env::test::set_caller(*player);
+                hotpotato.reveal_entropy(game_id, secret).unwrap();
+            }
+            ink::env::test::set_caller(accounts.alice);
+        }
+
+        /// Alice closes the lobby, everyone reveals and the game starts with
+        /// the first player holding
+        fn launch(hotpotato: &mut Hotpotato, game_id: GameId) -> Result<()> {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            hotpotato.close_lobby(game_id, None)?;
+            reveal_all(hotpotato, game_id);
+            hotpotato.start_game(game_id)
+        }
+
+        fn contract_address() -> H160 {
+            ink::env::test::callee()
+        }
+
+        fn balance_of(account: H160) -> U256 {
+            ink::env::test::get_contract_balance::<ink::env::DefaultEnvironment>(account)
+                .expect("account has no balance")
+        }
+
+        /// `player` joins with `value` attached; the off-chain environment
+        /// does not move value on its own, so the contract is credited here
+        fn join_paying(
+            hotpotato: &mut Hotpotato,
+            game_id: GameId,
+            player: H160,
+            value: Balance,
+            commitment: Hash,
+        ) -> Result<()> {
+            ink::env::test::set_caller(player);
+            ink::env::test::set_value_transferred(U256::from(value));
+            let result = hotpotato.join_game(game_id, commitment);
+            if result.is_ok() {
+                let contract = contract_address();
+                ink::env::test::set_contract_balance(
+                    contract,
+                    balance_of(contract) + U256::from(value),
+                );
+            }
+            ink::env::test::set_value_transferred(U256::zero());
+            result
+        }
+
+        /// Alice opens a game with a 100 entry fee that Bob, Charlie and
+        /// Django pay into
+        fn open_paid_lobby(hotpotato: &mut Hotpotato, mode: GameMode) -> GameId {
+            let config = GameConfig {
+                mode,
+                entry_fee: 100,
+                ..GameConfig::default()
+            };
+            open_paid_lobby_with(hotpotato, config)
+        }
+
+        /// Alice opens a game with `config` that Bob, Charlie and Django pay
+        /// into
+        fn open_paid_lobby_with(hotpotato: &mut Hotpotato, config: GameConfig) -> GameId {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let entry_fee = config.entry_fee;
+            let game_id = hotpotato.create_game(config).unwrap();
+            let players = [accounts.bob, accounts.charlie, accounts.django];
+            for player in players {
+                let commitment = commitment_for(game_id, &players, player);
+                join_paying(hotpotato, game_id, player, entry_fee, commitment).unwrap();
+            }
+            ink::env::test::set_caller(accounts.alice);
+            game_id
+        }
+
+        /// Bob and Charlie join Alice's game, which starts with Bob holding
+        fn start_default_game(hotpotato: &mut Hotpotato) -> GameId {
+            let accounts = ink::env::test::default_accounts();
+            let game_id = open_lobby(hotpotato, &[accounts.bob, accounts.charlie]);
+            launch(hotpotato, game_id).unwrap();
+            game_id
+        }
+
+        #[ink::test]
+        fn new_works() {
+            let hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.get_default_deadline(),
+                Deadline::constant(10, DeadlineUnit::Blocks)
+            );
+            assert_eq!(hotpotato.get_next_game_id(), 0);
+            assert_eq!(hotpotato.get_active_game_count(), 0);
+            assert!(!hotpotato.is_active(0));
+            assert_eq!(hotpotato.get_holder(0, 0), None);
+        }
+
+        #[ink::test]
+        fn create_game_opens_lobby() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(hotpotato.create_game(GameConfig::default()), Ok(0));
+            assert_eq!(
+                hotpotato.create_game(GameConfig {
+                    min_players: 3,
+                    max_players: 3,
+                    mode: GameMode::Elimination,
+                    ..GameConfig::default()
+                }),
+                Ok(1)
+            );
+
+            assert_eq!(hotpotato.get_status(0), Some(GameStatus::Lobby));
+            assert!(!hotpotato.is_active(0));
+            assert_eq!(hotpotato.get_game_starter(0), Some(accounts.alice));
+            assert_eq!(hotpotato.get_players(0), Vec::<H160>::new());
+            assert_eq!(hotpotato.get_next_game_id(), 2);
+        }
+
+        #[ink::test]
+        fn create_game_invalid_limits_fails() {
+            let mut hotpotato = Hotpotato::new(10);
+
+            for (min_players, max_players) in [(1, 4), (3, 2), (2, MAX_PLAYERS + 1)] {
+                let config = GameConfig {
+                    min_players,
+                    max_players,
+                    ..GameConfig::default()
+                };
+                assert_eq!(
+                    hotpotato.create_game(config),
+                    Err(HotPotatoError::InvalidPlayerLimits)
+                );
+            }
+            assert_eq!(hotpotato.get_next_game_id(), 0);
+        }
+
+        #[ink::test]
+        fn join_and_leave_work() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob, accounts.charlie]);
+
+            assert_eq!(
+                hotpotato.get_players(game_id),
+                vec![accounts.bob, accounts.charlie]
+            );
+            assert!(hotpotato.is_registered(game_id, accounts.bob));
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.leave_game(game_id), Ok(()));
+            assert_eq!(hotpotato.get_players(game_id), vec![accounts.charlie]);
+            assert!(!hotpotato.is_registered(game_id, accounts.bob));
+        }
+
+        #[ink::test]
+        fn join_twice_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob]);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.join_game(game_id, any_commitment(accounts.bob)),
+                Err(HotPotatoError::AlreadyRegistered)
+            );
+        }
+
+        #[ink::test]
+        fn join_full_lobby_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+
+            ink::env::test::set_caller(accounts.alice);
+            let config = GameConfig {
+                max_players: 2,
+                ..GameConfig::default()
+            };
+            let game_id = hotpotato.create_game(config).unwrap();
+            for player in [accounts.bob, accounts.charlie] {
+                ink::env::test::set_caller(player);
+                assert_eq!(hotpotato.join_game(game_id, any_commitment(player)), Ok(()));
+            }
+
+            ink::env::test::set_caller(accounts.django);
+            assert_eq!(
+                hotpotato.join_game(game_id, any_commitment(accounts.django)),
+                Err(HotPotatoError::LobbyFull)
+            );
+        }
+
+        #[ink::test]
+        fn leave_without_joining_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob]);
+
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(
+                hotpotato.leave_game(game_id),
+                Err(HotPotatoError::NotRegistered)
+            );
+        }
+
+        #[ink::test]
+        fn join_after_start_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            ink::env::test::set_caller(accounts.django);
+            assert_eq!(
+                hotpotato.join_game(game_id, any_commitment(accounts.django)),
+                Err(HotPotatoError::NotInLobby)
+            );
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.leave_game(game_id),
+                Err(HotPotatoError::NotInLobby)
+            );
+        }
+
+        #[ink::test]
+        fn start_game_works() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob, accounts.charlie]);
+
+            // Start game
+            assert_eq!(launch(&mut hotpotato, game_id), Ok(()));
+
+            // Verify state
+            assert!(hotpotato.is_active(game_id));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Active));
+            assert_eq!(hotpotato.get_holder(game_id, 0), Some(accounts.bob));
+            assert_eq!(hotpotato.get_game_starter(game_id), Some(accounts.alice));
+        }
+
+        #[ink::test]
+        fn close_lobby_not_enough_players_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob]);
+
+            assert_eq!(
+                hotpotato.close_lobby(game_id, None),
+                Err(HotPotatoError::NotEnoughPlayers)
+            );
+        }
+
+        #[ink::test]
+        fn close_lobby_not_starter_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob, accounts.charlie]);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.close_lobby(game_id, None),
+                Err(HotPotatoError::OnlyStarter)
+            );
+        }
+
+        #[ink::test]
+        fn start_game_twice_fails() {
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            assert_eq!(
+                hotpotato.close_lobby(game_id, None),
+                Err(HotPotatoError::NotInLobby)
+            );
+            assert_eq!(
+                hotpotato.start_game(game_id),
+                Err(HotPotatoError::NotSeeding)
+            );
+        }
+
+        #[ink::test]
+        fn beacon_collects_reveals_before_start() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let players = [accounts.bob, accounts.charlie, accounts.django];
+            let game_id = open_lobby(&mut hotpotato, &players);
+            let secrets = entropy_secrets(game_id, &players);
+
+            assert_eq!(
+                hotpotato.start_game(game_id),
+                Err(HotPotatoError::NotSeeding)
+            );
+            assert_eq!(hotpotato.close_lobby(game_id, None), Ok(()));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Seeding));
+
+            // Nobody joins or leaves while the beacon is seeded
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.leave_game(game_id),
+                Err(HotPotatoError::NotInLobby)
+            );
+
+            // Reveals are checked against the caller's own commitment
+            assert_eq!(
+                hotpotato.reveal_entropy(game_id, secrets[1]),
+                Err(HotPotatoError::InvalidEntropyReveal)
+            );
+            assert_eq!(hotpotato.reveal_entropy(game_id, secrets[0]), Ok(()));
+            assert_eq!(
+                hotpotato.reveal_entropy(game_id, secrets[0]),
+                Err(HotPotatoError::AlreadyRevealed)
+            );
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(
+                hotpotato.reveal_entropy(game_id, secrets[0]),
+                Err(HotPotatoError::NotRegistered)
+            );
+
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(hotpotato.reveal_entropy(game_id, secrets[1]), Ok(()));
+            assert_eq!(
+                hotpotato.start_game(game_id),
+                Err(HotPotatoError::EntropyPending)
+            );
+
+            ink::env::test::set_caller(accounts.django);
+            assert_eq!(hotpotato.reveal_entropy(game_id, secrets[2]), Ok(()));
+
+            // Anyone can start once every secret is in
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(hotpotato.start_game(game_id), Ok(()));
+
+            let mix = secrets
+                .iter()
+                .fold(Hash::default(), |mix, secret| mix_entropy(&mix, secret));
+            let seed = beacon_seed(game_id, &mix);
+            let game = hotpotato.get_game(game_id).unwrap();
+            assert_eq!(game.beacon.seed, Some(seed));
+            let holder = hotpotato.get_holder(game_id, 0);
+            assert_eq!(holder, Some(players[seat_for(&seed, 3)]));
+
+            let started: GameStarted = decode_event(recorded_events().last().unwrap());
+            assert_eq!(started.starter, accounts.alice);
+            assert_eq!(Some(started.holder), holder);
+        }
+
+        #[ink::test]
+        fn reveal_after_window_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let players = [accounts.bob, accounts.charlie];
+            let game_id = open_lobby(&mut hotpotato, &players);
+            hotpotato.close_lobby(game_id, None).unwrap();
+
+            advance_blocks(3);
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.reveal_entropy(game_id, entropy_secrets(game_id, &players)[0]),
+                Err(HotPotatoError::RevealWindowClosed)
+            );
+        }
+
+        #[ink::test]
+        fn withheld_entropy_forfeits_stake() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let players = [accounts.bob, accounts.charlie, accounts.django];
+            let game_id = open_paid_lobby(&mut hotpotato, GameMode::Classic);
+            let secrets = entropy_secrets(game_id, &players);
+            hotpotato.close_lobby(game_id, None).unwrap();
+
+            // Django keeps his secret to himself
+            for (player, secret) in players.iter().zip(&secrets).take(2) {
+                ink::env::test::set_caller(*player);
+                hotpotato.reveal_entropy(game_id, *secret).unwrap();
+            }
+
+            advance_blocks(3);
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(hotpotato.start_game(game_id), Ok(()));
+
+            let events = recorded_events();
+            let withheld: EntropyWithheld = decode_event(&events[events.len() - 2]);
+            assert_eq!(withheld.player, accounts.django);
+
+            // The draw only uses what was revealed
+            let mix = mix_entropy(&secrets[0], &secrets[1]);
+            let seed = beacon_seed(game_id, &mix);
+            assert_eq!(
+                hotpotato.get_holder(game_id, 0),
+                Some(players[seat_for(&seed, 2)])
+            );
+            assert_eq!(hotpotato.get_players(game_id), players[..2].to_vec());
+            assert!(!hotpotato.is_registered(game_id, accounts.django));
+
+            // Django's fee stays in the pool for the others
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+            assert_eq!(hotpotato.claimable_of(accounts.bob), 150);
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 150);
+            assert_eq!(hotpotato.claimable_of(accounts.django), 0);
+        }
+
+        #[ink::test]
+        fn withholding_below_min_players_cancels() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let config = GameConfig {
+                min_players: 3,
+                entry_fee: 100,
+                ..GameConfig::default()
+            };
+            let game_id = open_paid_lobby_with(&mut hotpotato, config);
+            let players = [accounts.bob, accounts.charlie, accounts.django];
+            hotpotato.close_lobby(game_id, None).unwrap();
+
+            ink::env::test::set_caller(accounts.bob);
+            hotpotato
+                .reveal_entropy(game_id, entropy_secrets(game_id, &players)[0])
+                .unwrap();
+
+            advance_blocks(3);
+            assert_eq!(hotpotato.start_game(game_id), Ok(()));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
+            assert_eq!(hotpotato.claimable_of(accounts.bob), 300);
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 0);
+        }
+
+        #[ink::test]
+        fn no_reveals_refunds_everyone() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = open_paid_lobby(&mut hotpotato, GameMode::Classic);
+            hotpotato.close_lobby(game_id, None).unwrap();
+
+            advance_blocks(3);
+            assert_eq!(hotpotato.start_game(game_id), Ok(()));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
+            for player in [accounts.bob, accounts.charlie, accounts.django] {
+                assert_eq!(hotpotato.claimable_of(player), 100);
+            }
+        }
+
+        #[ink::test]
+        fn pass_potato_works() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            // Bob passes to Charlie
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.charlie), Ok(()));
+
+            assert_eq!(hotpotato.get_holder(game_id, 0), Some(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn pass_potato_wrong_holder_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            // Charlie tries to pass (but he's not holder)
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.bob),
+                Err(HotPotatoError::NotCurrentHolder)
+            );
+        }
+
+        #[ink::test]
+        fn pass_potato_to_unregistered_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.django),
+                Err(HotPotatoError::RecipientNotRegistered)
+            );
+            assert_eq!(hotpotato.get_holder(game_id, 0), Some(accounts.bob));
+        }
+
+        #[ink::test]
+        fn pass_potato_unknown_game_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(7, 0, accounts.charlie),
+                Err(HotPotatoError::GameNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn pass_potato_in_lobby_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob, accounts.charlie]);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::GameNotActive)
+            );
+        }
+
+        #[ink::test]
+        fn pass_potato_ended_game_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::GameNotActive)
+            );
+        }
+
+        #[ink::test]
+        fn pass_potato_after_deadline_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_default_game(&mut hotpotato);
+
+            advance_blocks(3);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::DeadlinePassed)
+            );
+        }
+
+        #[ink::test]
+        fn games_are_independent() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let first = start_default_game(&mut hotpotato);
+            let second = start_default_game(&mut hotpotato);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.pass_potato(first, 0, accounts.charlie), Ok(()));
+
+            assert_eq!(hotpotato.get_holder(first, 0), Some(accounts.charlie));
+            assert_eq!(hotpotato.get_holder(second, 0), Some(accounts.bob));
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(hotpotato.end_game(second), Ok(()));
+            assert!(hotpotato.is_active(first));
+            assert!(!hotpotato.is_active(second));
+        }
+
+        #[ink::test]
+        fn end_game_works() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            // Alice ends the game she started
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+
+            assert!(!hotpotato.is_active(game_id));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
+            assert_eq!(hotpotato.get_holder(game_id, 0), None);
+            assert_eq!(hotpotato.get_game_starter(game_id), Some(accounts.alice));
+        }
+
+        #[ink::test]
+        fn end_game_cancels_lobby() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob]);
+
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
+            assert_eq!(
+                hotpotato.end_game(game_id),
+                Err(HotPotatoError::GameNotActive)
+            );
+        }
+
+        #[ink::test]
+        fn end_game_not_starter_fails() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.end_game(game_id),
+                Err(HotPotatoError::OnlyStarter)
+            );
+            assert!(hotpotato.is_active(game_id));
+        }
+
+        #[ink::test]
+        fn check_deadline_no_active_game() {
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.check_deadline(0),
+                Err(HotPotatoError::GameNotFound)
+            );
+
+            let game_id = start_default_game(&mut hotpotato);
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+            assert_eq!(
+                hotpotato.check_deadline(game_id),
+                Err(HotPotatoError::GameNotActive)
+            );
+        }
+
+        #[ink::test]
+        fn check_deadline_burns_after_expiry() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_default_game(&mut hotpotato);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(false));
+
+            advance_blocks(3);
+
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert!(!hotpotato.is_active(game_id));
+
+            let game = hotpotato.get_game(game_id).unwrap();
+            assert_eq!(game.players, vec![accounts.charlie]);
+            assert_eq!(game.eliminated, vec![accounts.bob]);
+            assert_eq!(hotpotato.get_winner(game_id), Some(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn player_stats_track_passes_burns_and_wins() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.get_player_stats(accounts.bob),
+                PlayerStats::default()
+            );
+            let game_id = start_default_game(&mut hotpotato);
+
+            advance_blocks(3);
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.charlie), Ok(()));
+            advance_blocks(1);
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.bob), Ok(()));
+
+            advance_blocks(11);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+
+            assert_eq!(
+                hotpotato.get_player_stats(accounts.bob),
+                PlayerStats {
+                    games_played: 1,
+                    passes: 1,
+                    burns: 1,
+                    wins: 0,
+                    blocks_held: 14,
+                    fastest_pass: Some(3),
+                }
+            );
+            assert_eq!(
+                hotpotato.get_player_stats(accounts.charlie),
+                PlayerStats {
+                    games_played: 1,
+                    passes: 1,
+                    burns: 0,
+                    wins: 1,
+                    blocks_held: 1,
+                    fastest_pass: Some(1),
+                }
+            );
+        }
+
+        #[ink::test]
+        fn end_game_credits_hold_without_win() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            advance_blocks(5);
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+
+            let bob = hotpotato.get_player_stats(accounts.bob);
+            assert_eq!(bob.blocks_held, 5);
+            assert_eq!(bob.wins, 0);
+            assert_eq!(bob.fastest_pass, None);
+            assert_eq!(hotpotato.get_player_stats(accounts.charlie).wins, 0);
+            assert_eq!(hotpotato.get_player_stats(accounts.charlie).games_played, 1);
+        }
+
+        /// Bob and Charlie play, Bob burns; then Charlie and Django play,
+        /// Charlie burns
+        fn play_two_games(hotpotato: &mut Hotpotato) {
+            let accounts = ink::env::test::default_accounts();
+            let first = start_default_game(hotpotato);
+            let second = open_lobby(hotpotato, &[accounts.charlie, accounts.django]);
+            launch(hotpotato, second).unwrap();
+
+            advance_blocks(11);
+            assert_eq!(hotpotato.check_deadline(first), Ok(true));
+            assert_eq!(hotpotato.check_deadline(second), Ok(true));
+        }
+
+        fn entry(player: H160, value: u64) -> LeaderboardEntry {
+            LeaderboardEntry { player, value }
+        }
+
+        #[ink::test]
+        fn leaderboards_rank_resolved_games() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            play_two_games(&mut hotpotato);
+
+            assert_eq!(
+                hotpotato.get_leaderboard(LeaderboardMetric::Wins, 10),
+                vec![entry(accounts.charlie, 1), entry(accounts.django, 1)]
+            );
+            assert_eq!(
+                hotpotato.get_leaderboard(LeaderboardMetric::Streak, 10),
+                vec![entry(accounts.django, 1)]
+            );
+            assert_eq!(
+                hotpotato.get_leaderboard(LeaderboardMetric::Score, 10),
+                vec![entry(accounts.charlie, 4), entry(accounts.django, 4)]
+            );
+            assert_eq!(
+                hotpotato.get_standing(accounts.charlie),
+                Standing {
+                    streak: 0,
+                    score: SURVIVAL_POINTS + WIN_POINTS,
+                }
+            );
+            assert_eq!(hotpotato.get_standing(accounts.bob), Standing::default());
+        }
+
+        #[ink::test]
+        fn leaderboard_size_is_bounded() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(hotpotato.get_leaderboard_size(), DEFAULT_LEADERBOARD_SIZE);
+            play_two_games(&mut hotpotato);
+
+            for size in [0, MAX_LEADERBOARD_SIZE + 1] {
+                assert_eq!(
+                    hotpotato.set_leaderboard_size(size),
+                    Err(HotPotatoError::InvalidLeaderboardSize)
+                );
+            }
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.set_leaderboard_size(1),
+                Err(HotPotatoError::OnlyOwner)
+            );
+
+            assert_eq!(
+                hotpotato.get_leaderboard(LeaderboardMetric::Score, 1),
+                vec![entry(accounts.charlie, 4)]
+            );
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(hotpotato.set_leaderboard_size(1), Ok(()));
+            assert_eq!(hotpotato.get_leaderboard_size(), 1);
+            assert_eq!(
+                hotpotato.get_leaderboard(LeaderboardMetric::Wins, 10),
+                vec![entry(accounts.charlie, 1)]
+            );
+        }
+
+        #[ink::test]
+        fn pass_history_records_every_pass() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+            let started = u32::try_from(hotpotato.get_last_passed_at(game_id, 0)).unwrap();
+
+            advance_blocks(3);
+            pass_back_and_forth(&mut hotpotato, game_id, 1);
+            advance_blocks(2);
+            pass_back_and_forth(&mut hotpotato, game_id, 2);
+
+            let history = hotpotato.get_pass_history(game_id, 0, 10);
+            assert_eq!(history.len(), 3);
+            assert_eq!(
+                history[0],
+                PassRecord {
+                    potato: 0,
+                    from: accounts.bob,
+                    to: accounts.charlie,
+                    block: started + 3,
+                    at: u64::from(started + 3),
+                    remaining: 7,
+                }
+            );
+            assert_eq!(history[1].from, accounts.charlie);
+            assert_eq!(history[1].remaining, 8);
+            assert_eq!(history[2].to, accounts.charlie);
+            assert_eq!(history[2].remaining, 10);
+
+            // Pages start where asked and never read past the end
+            assert_eq!(
+                hotpotato.get_pass_history(game_id, 1, 1),
+                vec![history[1].clone()]
+            );
+            assert_eq!(
+                hotpotato.get_pass_history(game_id, 2, 10),
+                vec![history[2].clone()]
+            );
+            assert_eq!(hotpotato.get_pass_history(game_id, 3, 10), vec![]);
+            assert_eq!(
+                hotpotato.get_pass_log(game_id),
+                PassLog { pruned: 0, len: 3 }
+            );
+        }
+
+        #[ink::test]
+        fn pass_history_prunes_after_game_ends() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+            pass_back_and_forth(&mut hotpotato, game_id, 3);
+            let history = hotpotato.get_pass_history(game_id, 0, 10);
+
+            assert_eq!(
+                hotpotato.prune_pass_history(game_id, 2),
+                Err(HotPotatoError::GameNotEnded)
+            );
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+            assert_eq!(hotpotato.prune_pass_history(game_id, 2), Ok(2));
+            assert_eq!(
+                hotpotato.get_pass_log(game_id),
+                PassLog { pruned: 2, len: 3 }
+            );
+            assert_eq!(
+                hotpotato.get_pass_history(game_id, 0, 10),
+                vec![history[2].clone()]
+            );
+
+            assert_eq!(hotpotato.prune_pass_history(game_id, 5), Ok(1));
+            assert_eq!(hotpotato.prune_pass_history(game_id, 5), Ok(0));
+            assert_eq!(hotpotato.get_pass_history(game_id, 0, 10), vec![]);
+        }
+
+        #[ink::test]
+        fn self_pass_is_forbidden_by_default() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.bob),
+                Err(HotPotatoError::SelfPass)
+            );
+
+            ink::env::test::set_caller(accounts.alice);
+            let rules = PassRules {
+                allow_self_pass: true,
+                ..PassRules::default()
+            };
+            assert_eq!(hotpotato.set_pass_rules(rules.clone()), Ok(()));
+            assert_eq!(hotpotato.get_pass_rules(), rules);
+            let changed: ConfigChanged = decode_event(recorded_events().last().unwrap());
+            assert_eq!(changed.change, ConfigChange::PassRules(rules));
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.bob), Ok(()));
+        }
+
+        #[ink::test]
+        fn pass_back_window_blocks_recent_passers() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.set_pass_rules(PassRules {
+                    pass_back_window: MAX_PASS_BACK_WINDOW + 1,
+                    ..PassRules::default()
+                }),
+                Err(HotPotatoError::InvalidPassRules)
+            );
+            assert_eq!(
+                hotpotato.set_pass_rules(PassRules {
+                    pass_back_window: 2,
+                    ..PassRules::default()
+                }),
+                Ok(())
+            );
+            let game_id = open_lobby(
+                &mut hotpotato,
+                &[accounts.bob, accounts.charlie, accounts.django],
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.charlie), Ok(()));
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.bob),
+                Err(HotPotatoError::PassBackTooSoon)
+            );
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.django), Ok(()));
+
+            // Bob passed two passes ago, Charlie just now
+            ink::env::test::set_caller(accounts.django);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.bob),
+                Err(HotPotatoError::PassBackTooSoon)
+            );
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::PassBackTooSoon)
+            );
+        }
+
+        #[ink::test]
+        fn receive_limit_caps_passes_to_a_player() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.set_pass_rules(PassRules {
+                    max_receives: 1,
+                    ..PassRules::default()
+                }),
+                Ok(())
+            );
+            let game_id = start_default_game(&mut hotpotato);
+
+            // Bob's first hold was not received, so he can get it once
+            pass_back_and_forth(&mut hotpotato, game_id, 2);
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::ReceiveLimitReached)
+            );
+        }
+
+        #[ink::test]
+        fn min_hold_must_fit_the_deadline() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            let limits = TimeLimits {
+                min_hold: 4,
+                ..TimeLimits::default()
+            };
+            assert_eq!(hotpotato.set_time_limits(limits), Ok(()));
+
+            assert_eq!(
+                hotpotato.set_default_deadline(Deadline::constant(4, DeadlineUnit::Blocks)),
+                Err(HotPotatoError::InvalidDeadline)
+            );
+            let config = |deadline| GameConfig {
+                deadline: Some(deadline),
+                ..GameConfig::default()
+            };
+            assert_eq!(
+                hotpotato.create_game(config(Deadline::constant(3, DeadlineUnit::Blocks))),
+                Err(HotPotatoError::InvalidDeadline)
+            );
+            assert!(hotpotato
+                .create_game(config(Deadline::constant(5, DeadlineUnit::Blocks)))
+                .is_ok());
+
+            // The minimum hold is in blocks, so timestamp deadlines are not
+            // compared with it
+            assert!(hotpotato
+                .create_game(config(Deadline::constant(3, DeadlineUnit::Milliseconds)))
+                .is_ok());
+        }
+
+        #[ink::test]
+        fn min_hold_delays_passes() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.set_time_limits(TimeLimits {
+                    min_hold: 5,
+                    max_game_length: 5,
+                    ..TimeLimits::default()
+                }),
+                Err(HotPotatoError::InvalidTimeLimits)
+            );
+            assert_eq!(
+                hotpotato.set_time_limits(TimeLimits {
+                    min_hold: 10,
+                    ..TimeLimits::default()
+                }),
+                Err(HotPotatoError::InvalidTimeLimits)
+            );
+            let limits = TimeLimits {
+                min_hold: 3,
+                ..TimeLimits::default()
+            };
+            assert_eq!(hotpotato.set_time_limits(limits.clone()), Ok(()));
+            assert_eq!(hotpotato.get_time_limits(), limits);
+            let changed: ConfigChanged = decode_event(recorded_events().last().unwrap());
+            assert_eq!(changed.change, ConfigChange::TimeLimits(limits));
+            let game_id = start_default_game(&mut hotpotato);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::HoldTooShort)
+            );
+            advance_blocks(3);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.charlie), Ok(()));
+
+            // The hold restarts with every pass
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.bob),
+                Err(HotPotatoError::HoldTooShort)
+            );
+        }
+
+        #[ink::test]
+        fn game_length_cap_burns_the_holder() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.set_time_limits(TimeLimits {
+                    max_game_length: 6,
+                    ..TimeLimits::default()
+                }),
+                Ok(())
+            );
+            let game_id = open_lobby(
+                &mut hotpotato,
+                &[accounts.bob, accounts.charlie, accounts.django],
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+            assert_eq!(hotpotato.get_game_length(game_id), Some(0));
+
+            // Passing keeps every deadline fresh, but not the game clock
+            for _ in 0..3 {
+                advance_blocks(2);
+                pass_back_and_forth(&mut hotpotato, game_id, 1);
+            }
+            advance_blocks(1);
+            assert_eq!(hotpotato.get_game_length(game_id), Some(7));
+            let holder = hotpotato.get_holder(game_id, 0).unwrap();
+            ink::env::test::set_caller(holder);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.django),
+                Err(HotPotatoError::GameLengthCapped)
+            );
+
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            let game = hotpotato.get_game(game_id).unwrap();
+            assert_eq!(game.status, GameStatus::Ended);
+            assert_eq!(game.eliminated, vec![holder]);
+            assert_eq!(game.winner, None);
+            assert_eq!(hotpotato.get_game_length(game_id), None);
+
+            let events = recorded_events();
+            let capped: GameCapped = decode_event(&events[events.len() - 3]);
+            assert_eq!(capped.losers, vec![holder]);
+            assert_eq!(capped.tie_break, TieBreak::Holder);
+            assert_eq!(capped.length, 7);
+            assert_eq!(hotpotato.get_player_stats(holder).burns, 1);
+        }
+
+        #[ink::test]
+        fn tie_break_picks_the_capped_loser() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.set_time_limits(TimeLimits {
+                    max_game_length: 2,
+                    tie_break: TieBreak::MostReceived,
+                    ..TimeLimits::default()
+                }),
+                Ok(())
+            );
+            let game_id = open_lobby(
+                &mut hotpotato,
+                &[accounts.bob, accounts.charlie, accounts.django],
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+
+            // Charlie receives twice and ends up passing to Django
+            pass_back_and_forth(&mut hotpotato, game_id, 3);
+            ink::env::test::set_caller(accounts.charlie);
+            hotpotato.pass_potato(game_id, 0, accounts.django).unwrap();
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            let game = hotpotato.get_game(game_id).unwrap();
+            assert_eq!(game.eliminated, vec![accounts.charlie]);
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(
+                hotpotato.set_time_limits(TimeLimits {
+                    max_game_length: 2,
+                    tie_break: TieBreak::Split,
+                    ..TimeLimits::default()
+                }),
+                Ok(())
+            );
+            let game_id = start_default_game(&mut hotpotato);
+            advance_blocks(2);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(false));
+            advance_blocks(1);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            let game = hotpotato.get_game(game_id).unwrap();
+            assert_eq!(game.status, GameStatus::Ended);
+            assert!(game.eliminated.is_empty());
+            assert_eq!(game.players, vec![accounts.bob, accounts.charlie]);
+        }
+
+        #[ink::test]
+        fn tie_break_counts_every_potato_holder() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.set_time_limits(TimeLimits {
+                    max_game_length: 2,
+                    tie_break: TieBreak::MostReceived,
+                    ..TimeLimits::default()
+                }),
+                Ok(())
+            );
+            let config = GameConfig {
+                potatoes: 2,
+                ..GameConfig::default()
+            };
+            let game_id = open_lobby_with(
+                &mut hotpotato,
+                config,
+                &[
+                    accounts.bob,
+                    accounts.charlie,
+                    accounts.django,
+                    accounts.eve,
+                ],
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+
+            // Django and Eve received once each; Django holds the second
+            // potato from the earlier seat
+            ink::env::test::set_caller(accounts.bob);
+            hotpotato.pass_potato(game_id, 0, accounts.eve).unwrap();
+            ink::env::test::set_caller(accounts.charlie);
+            hotpotato.pass_potato(game_id, 1, accounts.django).unwrap();
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            let game = hotpotato.get_game(game_id).unwrap();
+            assert_eq!(game.eliminated, vec![accounts.django]);
+        }
+
+        #[ink::test]
+        fn team_games_need_team_setup() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            let teams = TeamConfig {
+                teams: 2,
+                rounds: 2,
+                max_burns: 0,
+            };
+            let config = GameConfig {
+                mode: GameMode::Teams,
+                ..GameConfig::default()
+            };
+            assert_eq!(
+                hotpotato.create_game(config.clone()),
+                Err(HotPotatoError::InvalidTeamConfig)
+            );
+            assert_eq!(
+                hotpotato.create_team_game(GameConfig::default(), teams.clone()),
+                Err(HotPotatoError::InvalidTeamConfig)
+            );
+            assert_eq!(
+                hotpotato.create_team_game(
+                    config.clone(),
+                    TeamConfig {
+                        teams: MAX_TEAMS + 1,
+                        ..teams.clone()
+                    }
+                ),
+                Err(HotPotatoError::InvalidTeamConfig)
+            );
+
+            let classic_id = hotpotato.create_game(GameConfig::default()).unwrap();
+            let game_id = hotpotato.create_team_game(config, teams.clone()).unwrap();
+            assert_eq!(hotpotato.get_team_config(game_id), Some(teams));
+            let commitment = Hash::from([1; 32]);
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.join_game(game_id, commitment),
+                Err(HotPotatoError::TeamRequired)
+            );
+            assert_eq!(
+                hotpotato.join_team(game_id, 2, commitment),
+                Err(HotPotatoError::InvalidTeam)
+            );
+            assert_eq!(
+                hotpotato.join_team(classic_id, 0, commitment),
+                Err(HotPotatoError::NotTeamGame)
+            );
+            assert_eq!(hotpotato.join_team(game_id, 1, commitment), Ok(()));
+            assert_eq!(hotpotato.get_team(game_id, accounts.bob), Some(1));
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(hotpotato.join_team(game_id, 1, commitment), Ok(()));
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(
+                hotpotato.close_lobby(game_id, None),
+                Err(HotPotatoError::NotEnoughTeams)
+            );
+            assert_eq!(hotpotato.set_mode_enabled(GameMode::Teams, false), Ok(()));
+            assert!(!hotpotato.get_settings().teams_enabled);
+            assert_eq!(
+                hotpotato.create_team_game(
+                    GameConfig {
+                        mode: GameMode::Teams,
+                        ..GameConfig::default()
+                    },
+                    TeamConfig {
+                        teams: 2,
+                        rounds: 2,
+                        max_burns: 0,
+                    }
+                ),
+                Err(HotPotatoError::ModeDisabled)
+            );
+        }
+
+        #[ink::test]
+        fn team_game_pays_the_least_burned_team() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_team_lobby(
+                &mut hotpotato,
+                TeamConfig {
+                    teams: 2,
+                    rounds: 2,
+                    max_burns: 0,
+                },
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+
+            // Bob and Charlie play for team 0, Django and Eve for team 1
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::SameTeam)
+            );
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.django), Ok(()));
+
+            // Django burns, the seat after him starts round two and burns too
+            advance_blocks(11);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            let burned: TeamBurned = decode_event(&recorded_events()[recorded_events().len() - 2]);
+            assert_eq!(
+                (burned.holder, burned.team, burned.burns),
+                (accounts.django, 1, 1)
+            );
+            let game = hotpotato.get_game(game_id).unwrap();
+            assert_eq!(game.status, GameStatus::Active);
+            assert_eq!(game.round, 2);
+            assert!(game.eliminated.is_empty());
+            assert_eq!(hotpotato.get_holder(game_id, 0), Some(accounts.eve));
+
+            advance_blocks(11);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert_eq!(
+                hotpotato.get_game(game_id).unwrap().status,
+                GameStatus::Ended
+            );
+            assert_eq!(
+                hotpotato.get_team_standings(game_id),
+                vec![
+                    TeamStanding {
+                        team: 0,
+                        members: vec![accounts.bob, accounts.charlie],
+                        burns: 0,
+                    },
+                    TeamStanding {
+                        team: 1,
+                        members: vec![accounts.django, accounts.eve],
+                        burns: 2,
+                    },
+                ]
+            );
+            assert_eq!(hotpotato.claimable_of(accounts.bob), 200);
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 200);
+            assert_eq!(hotpotato.claimable_of(accounts.django), 0);
+            assert_eq!(hotpotato.get_player_stats(accounts.charlie).wins, 1);
+            assert_eq!(hotpotato.get_player_stats(accounts.eve).burns, 1);
+        }
+
+        #[ink::test]
+        fn team_game_ends_at_max_burns() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_team_lobby(
+                &mut hotpotato,
+                TeamConfig {
+                    teams: 2,
+                    rounds: 5,
+                    max_burns: 1,
+                },
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+
+            advance_blocks(11);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            let game = hotpotato.get_game(game_id).unwrap();
+            assert_eq!(game.status, GameStatus::Ended);
+            assert_eq!(game.round, 1);
+            assert_eq!(hotpotato.claimable_of(accounts.django), 200);
+            assert_eq!(hotpotato.claimable_of(accounts.eve), 200);
+
+            // Team 1 took no burns, so it leads the standings
+            let standings = hotpotato.get_team_standings(game_id);
+            assert_eq!((standings[0].team, standings[0].burns), (1, 0));
+            assert_eq!((standings[1].team, standings[1].burns), (0, 1));
+        }
+
+        #[ink::test]
+        fn potato_count_comes_from_the_config() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            for config in [
+                GameConfig {
+                    potatoes: 0,
+                    ..GameConfig::default()
+                },
+                GameConfig {
+                    potatoes: MAX_POTATOES + 1,
+                    ..GameConfig::default()
+                },
+                GameConfig {
+                    potatoes: 3,
+                    max_players: 3,
+                    ..GameConfig::default()
+                },
+                GameConfig {
+                    potatoes: 2,
+                    ..fuse_config(5, 20)
+                },
+            ] {
+                assert_eq!(
+                    hotpotato.create_game(config),
+                    Err(HotPotatoError::InvalidPotatoCount)
+                );
+            }
+
+            let config = GameConfig {
+                potatoes: 2,
+                ..GameConfig::default()
+            };
+            let game_id =
+                open_lobby_with(&mut hotpotato, config, &[accounts.bob, accounts.charlie]);
+            assert_eq!(hotpotato.get_potato_count(game_id), 2);
+            assert_eq!(hotpotato.get_holder(game_id, 1), None);
+
+            // Somebody has to be free to receive a potato
+            assert_eq!(
+                hotpotato.close_lobby(game_id, None),
+                Err(HotPotatoError::NotEnoughPlayers)
+            );
+        }
+
+        #[ink::test]
+        fn several_potatoes_pass_independently() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let config = GameConfig {
+                potatoes: 2,
+                ..GameConfig::default()
+            };
+            let game_id = open_lobby_with(
+                &mut hotpotato,
+                config,
+                &[accounts.bob, accounts.charlie, accounts.django],
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+            let holders = |hotpotato: &Hotpotato| -> Vec<Option<H160>> {
+                hotpotato
+                    .get_potatoes(game_id)
+                    .iter()
+                    .map(|potato| potato.holder)
+                    .collect()
+            };
+            assert_eq!(
+                holders(&hotpotato),
+                vec![Some(accounts.bob), Some(accounts.charlie)]
+            );
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 1, accounts.django),
+                Err(HotPotatoError::NotCurrentHolder)
+            );
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 2, accounts.django),
+                Err(HotPotatoError::InvalidPotato)
+            );
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::AlreadyHoldsPotato)
+            );
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.django), Ok(()));
+
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(hotpotato.pass_potato(game_id, 1, accounts.bob), Ok(()));
+            let passed: PotatoPassed = decode_event(recorded_events().last().unwrap());
+            assert_eq!((passed.potato, passed.to), (1, accounts.bob));
+            assert_eq!(
+                holders(&hotpotato),
+                vec![Some(accounts.django), Some(accounts.bob)]
+            );
+            assert_eq!(hotpotato.get_holder(game_id, 0), Some(accounts.django));
+            assert_eq!(hotpotato.get_holder(game_id, 1), Some(accounts.bob));
+            assert_eq!(hotpotato.get_holder(game_id, 2), None);
+            assert_eq!(
+                hotpotato
+                    .get_pass_history(game_id, 0, 10)
+                    .iter()
+                    .map(|record| record.potato)
+                    .collect::<Vec<_>>(),
+                vec![0, 1]
+            );
+        }
+
+        #[ink::test]
+        fn pass_rules_span_every_potato() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(
+                hotpotato.set_pass_rules(PassRules {
+                    pass_back_window: 2,
+                    max_receives: 1,
+                    ..PassRules::default()
+                }),
+                Ok(())
+            );
+            let config = GameConfig {
+                potatoes: 2,
+                ..GameConfig::default()
+            };
+            let game_id = open_lobby_with(
+                &mut hotpotato,
+                config,
+                &[
+                    accounts.bob,
+                    accounts.charlie,
+                    accounts.django,
+                    accounts.eve,
+                ],
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.django), Ok(()));
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(hotpotato.pass_potato(game_id, 1, accounts.eve), Ok(()));
+
+            // Charlie only ever passed the other potato, and Django only ever
+            // received this one
+            ink::env::test::set_caller(accounts.django);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::PassBackTooSoon)
+            );
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 1, accounts.django),
+                Err(HotPotatoError::AlreadyHoldsPotato)
+            );
+        }
+
+        #[ink::test]
+        fn check_deadline_burns_every_expired_potato() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let config = GameConfig {
+                mode: GameMode::Elimination,
+                potatoes: 2,
+                ..GameConfig::default()
+            };
+            let game_id = open_lobby_with(
+                &mut hotpotato,
+                config,
+                &[
+                    accounts.bob,
+                    accounts.charlie,
+                    accounts.django,
+                    accounts.eve,
+                    accounts.frank,
+                ],
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+
+            // Charlie's potato gets a fresh deadline, Bob's runs out
+            advance_blocks(5);
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(hotpotato.pass_potato(game_id, 1, accounts.eve), Ok(()));
+            advance_blocks(6);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert_eq!(hotpotato.get_elimination_order(game_id), vec![accounts.bob]);
+            assert_eq!(hotpotato.get_round(game_id), 2);
+            assert_eq!(
+                hotpotato
+                    .get_potatoes(game_id)
+                    .iter()
+                    .map(|potato| potato.holder)
+                    .collect::<Vec<_>>(),
+                vec![Some(accounts.charlie), Some(accounts.django)]
+            );
+            // Eve's unexpired hold still counts when the potatoes are dealt
+            // again
+            assert_eq!(hotpotato.get_player_stats(accounts.bob).blocks_held, 11);
+            assert_eq!(hotpotato.get_player_stats(accounts.eve).blocks_held, 6);
+
+            // Both potatoes expire together and burn in one call, leaving as
+            // many players as potatoes
+            advance_blocks(11);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            let burns: Vec<(Option<u32>, H160)> = recorded_events()
+                .iter()
+                .filter(|event| {
+                    event.topics.first().map(|topic| &topic[..])
+                        == <PotatoBurned as ink::env::Event>::SIGNATURE_TOPIC
+                            .as_ref()
+                            .map(|topic| &topic[..])
+                })
+                .map(|event| {
+                    let burned: PotatoBurned = decode_event(event);
+                    (burned.potato, burned.holder)
+                })
+                .collect();
+            assert_eq!(
+                burns,
+                vec![
+                    (Some(0), accounts.bob),
+                    (Some(0), accounts.charlie),
+                    (Some(1), accounts.django)
+                ]
+            );
+            assert_eq!(
+                hotpotato.get_elimination_order(game_id),
+                vec![accounts.bob, accounts.charlie, accounts.django]
+            );
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
+            assert_eq!(
+                hotpotato.get_players(game_id),
+                vec![accounts.eve, accounts.frank]
+            );
+            assert!(hotpotato
+                .get_potatoes(game_id)
+                .iter()
+                .all(|potato| potato.holder.is_none()));
+            assert_eq!(hotpotato.get_player_stats(accounts.charlie).blocks_held, 16);
+            assert_eq!(hotpotato.get_player_stats(accounts.django).blocks_held, 11);
+            assert_eq!(hotpotato.get_player_stats(accounts.eve).blocks_held, 6);
+        }
+
+        #[ink::test]
+        fn session_key_passes_for_the_holder() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+            let expires_at = ink::env::block_number::<ink::env::DefaultEnvironment>() + 5;
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.authorize_session_key(game_id, accounts.eve, expires_at),
+                Ok(())
+            );
+            let authorized: SessionKeyAuthorized = decode_event(recorded_events().last().unwrap());
+            assert_eq!(
+                (authorized.principal, authorized.delegate),
+                (accounts.bob, accounts.eve)
+            );
+            assert_eq!(
+                hotpotato.get_session_key(game_id, accounts.eve),
+                Some(SessionKey {
+                    principal: accounts.bob,
+                    expires_at,
+                })
+            );
+
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.charlie), Ok(()));
+            let passed: PotatoPassed = decode_event(recorded_events().last().unwrap());
+            assert_eq!(passed.from, accounts.bob);
+            assert_eq!(passed.delegate, Some(accounts.eve));
+            assert_eq!(hotpotato.get_holder(game_id, 0), Some(accounts.charlie));
+            assert_eq!(hotpotato.get_player_stats(accounts.bob).passes, 1);
+            assert_eq!(hotpotato.get_player_stats(accounts.eve).passes, 0);
+            assert_eq!(
+                hotpotato.get_pass_history(game_id, 0, 1)[0].from,
+                accounts.bob
+            );
+
+            // The key only acts for Bob
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.bob),
+                Err(HotPotatoError::NotCurrentHolder)
+            );
+        }
+
+        #[ink::test]
+        fn delegates_cannot_join_the_game() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob]);
+            let expires_at = ink::env::block_number::<ink::env::DefaultEnvironment>() + 5;
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.authorize_session_key(game_id, accounts.eve, expires_at),
+                Ok(())
+            );
+
+            // A delegate that joined could never pass a potato of its own
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(
+                hotpotato.join_game(game_id, any_commitment(accounts.eve)),
+                Err(HotPotatoError::DelegateCannotJoin)
+            );
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.revoke_session_key(game_id, accounts.eve), Ok(()));
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(
+                hotpotato.join_game(game_id, any_commitment(accounts.eve)),
+                Ok(())
+            );
+        }
+
+        #[ink::test]
+        fn session_keys_expire_and_can_be_revoked() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+            let current_block = ink::env::block_number::<ink::env::DefaultEnvironment>();
+
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(
+                hotpotato.authorize_session_key(game_id, accounts.frank, current_block + 2),
+                Err(HotPotatoError::NotRegistered)
+            );
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.authorize_session_key(game_id, accounts.charlie, current_block + 2),
+                Err(HotPotatoError::InvalidDelegate)
+            );
+            assert_eq!(
+                hotpotato.authorize_session_key(game_id, H160::zero(), current_block + 2),
+                Err(HotPotatoError::InvalidDelegate)
+            );
+            assert_eq!(
+                hotpotato.authorize_session_key(game_id, accounts.eve, current_block),
+                Err(HotPotatoError::InvalidExpiry)
+            );
+            assert_eq!(
+                hotpotato.authorize_session_key(game_id, accounts.eve, current_block + 2),
+                Ok(())
+            );
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(
+                hotpotato.authorize_session_key(game_id, accounts.eve, current_block + 2),
+                Err(HotPotatoError::SessionKeyTaken)
+            );
+            assert_eq!(
+                hotpotato.revoke_session_key(game_id, accounts.eve),
+                Err(HotPotatoError::NoSessionKey)
+            );
+
+            // Past its expiry block the key acts for nobody
+            advance_blocks(3);
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::NotCurrentHolder)
+            );
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.authorize_session_key(game_id, accounts.eve, current_block + 10),
+                Ok(())
+            );
+            assert_eq!(hotpotato.revoke_session_key(game_id, accounts.eve), Ok(()));
+            let revoked: SessionKeyRevoked = decode_event(recorded_events().last().unwrap());
+            assert_eq!(revoked.delegate, accounts.eve);
+            assert_eq!(hotpotato.get_session_key(game_id, accounts.eve), None);
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::NotCurrentHolder)
+            );
+        }
+
+        #[ink::test]
+        fn classic_burn_with_several_survivors_has_no_winner() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = open_lobby(
+                &mut hotpotato,
+                &[accounts.bob, accounts.charlie, accounts.django],
+            );
+            assert_eq!(launch(&mut hotpotato, game_id), Ok(()));
+
+            advance_blocks(3);
+
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
+            assert_eq!(hotpotato.get_winner(game_id), None);
+        }
+
+        /// Bob, Charlie and Django join an elimination game that starts with
+        /// Bob holding
+        fn start_elimination_game(hotpotato: &mut Hotpotato) -> GameId {
+            let accounts = ink::env::test::default_accounts();
+            let config = GameConfig {
+                mode: GameMode::Elimination,
+                ..GameConfig::default()
+            };
+            let game_id = open_lobby_with(
+                hotpotato,
+                config,
+                &[accounts.bob, accounts.charlie, accounts.django],
+            );
+            launch(hotpotato, game_id).unwrap();
+            game_id
+        }
+
+        #[ink::test]
+        fn elimination_burn_starts_next_round() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_elimination_game(&mut hotpotato);
+            assert_eq!(hotpotato.get_round(game_id), 1);
+
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+
+            // Bob is out, the seat after him gets the potato
+            assert!(hotpotato.is_active(game_id));
+            assert_eq!(hotpotato.get_round(game_id), 2);
+            assert_eq!(hotpotato.get_holder(game_id, 0), Some(accounts.charlie));
+            assert_eq!(hotpotato.get_elimination_order(game_id), vec![accounts.bob]);
+            assert_eq!(hotpotato.get_remaining_blocks(game_id, 0), 2);
+
+            // Eliminated players can no longer receive the potato
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.bob),
+                Err(HotPotatoError::RecipientEliminated)
+            );
+
+            let events = recorded_events();
+            let round: RoundStarted = decode_event(events.last().unwrap());
+            assert_eq!(round.game_id, game_id);
+            assert_eq!(round.holder, accounts.charlie);
+            assert_eq!(round.round, 2);
+            assert_eq!(round.block, 3);
+            assert_eq!(round.survivors, 2);
+        }
+
+        #[ink::test]
+        fn elimination_runs_until_one_survivor() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_elimination_game(&mut hotpotato);
+
+            // Round 1: Bob burns; round 2: Charlie burns
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
+            assert_eq!(hotpotato.get_winner(game_id), Some(accounts.django));
+            assert_eq!(
+                hotpotato.get_elimination_order(game_id),
+                vec![accounts.bob, accounts.charlie]
+            );
+            assert_eq!(hotpotato.get_active_game_count(), 0);
+
+            let events = recorded_events();
+            let ended: GameEnded = decode_event(events.last().unwrap());
+            assert_eq!(ended.holder, Some(accounts.charlie));
+            assert_eq!(ended.winner, Some(accounts.django));
+        }
+
+        #[ink::test]
+        fn rating_expected_scores() {
+            assert_eq!(rating::expected(1_500, 1_500), rating::SCALE / 2);
+            assert_eq!(rating::expected(1_900, 1_500), 909_090);
+            assert_eq!(rating::expected(1_500, 1_900), 90_910);
+            // The curve flattens out past an 800 point gap
+            assert_eq!(
+                rating::expected(2_900, 1_500),
+                rating::expected(2_300, 1_500)
+            );
+            assert_eq!(
+                rating::updated(&[1_500, 1_500], &[0, 1], RATING_K, MIN_RATING),
+                vec![1_516, 1_484]
+            );
+            assert_eq!(
+                rating::updated(&[1_500, 120], &[1, 0], RATING_K, MIN_RATING),
+                vec![1_469, 151]
+            );
+            assert_eq!(
+                rating::updated(&[105, 105], &[1, 0], RATING_K, MIN_RATING),
+                vec![MIN_RATING, 121]
+            );
+        }
+
+        #[ink::test]
+        fn ratings_follow_elimination_order() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            assert_eq!(hotpotato.get_rating(accounts.bob), INITIAL_RATING);
+            let game_id = start_elimination_game(&mut hotpotato);
+
+            // Bob burns first, then Charlie; ratings move only at the end
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert_eq!(hotpotato.get_rating(accounts.bob), INITIAL_RATING);
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+
+            assert_eq!(hotpotato.get_rating(accounts.django), 1_516);
+            assert_eq!(hotpotato.get_rating(accounts.charlie), 1_500);
+            assert_eq!(hotpotato.get_rating(accounts.bob), 1_484);
+        }
+
+        #[ink::test]
+        fn elimination_end_game_has_no_winner() {
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_elimination_game(&mut hotpotato);
+
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+            assert_eq!(hotpotato.get_winner(game_id), None);
+        }
+
+        #[ink::test]
+        fn get_active_games_paginates() {
+            let mut hotpotato = Hotpotato::new(10);
+
+            for _ in 0..5 {
+                hotpotato.create_game(GameConfig::default()).unwrap();
+            }
+
+            assert_eq!(hotpotato.get_active_game_count(), 5);
+            assert_eq!(hotpotato.get_active_games(0, 2), vec![0, 1]);
+            assert_eq!(hotpotato.get_active_games(2, 2), vec![2, 3]);
+            assert_eq!(hotpotato.get_active_games(4, 2), vec![4]);
+            assert_eq!(hotpotato.get_active_games(5, 2), Vec::<GameId>::new());
+
+            // Ending a game moves the last active game into its slot
+            assert_eq!(hotpotato.end_game(1), Ok(()));
+            assert_eq!(hotpotato.get_active_game_count(), 4);
+            assert_eq!(hotpotato.get_active_games(0, 10), vec![0, 4, 2, 3]);
+        }
+
+        #[ink::test]
+        fn get_active_games_caps_page_size() {
+            let mut hotpotato = Hotpotato::new(10);
+
+            for _ in 0..MAX_PAGE_SIZE + 1 {
+                hotpotato.create_game(GameConfig::default()).unwrap();
+            }
+
+            assert_eq!(
+                hotpotato.get_active_games(0, u32::MAX).len(),
+                MAX_PAGE_SIZE as usize
+            );
+        }
+
+        #[ink::test]
+        fn ownership_transfer_is_two_step() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            assert_eq!(hotpotato.get_owner(), accounts.alice);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.transfer_ownership(accounts.bob),
+                Err(HotPotatoError::OnlyOwner)
+            );
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(
+                hotpotato.transfer_ownership(H160::zero()),
+                Err(HotPotatoError::InvalidRecipient)
+            );
+            assert_eq!(hotpotato.transfer_ownership(accounts.bob), Ok(()));
+            assert_eq!(hotpotato.get_owner(), accounts.alice);
+            assert_eq!(hotpotato.get_pending_owner(), Some(accounts.bob));
+
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(
+                hotpotato.accept_ownership(),
+                Err(HotPotatoError::NotPendingOwner)
+            );
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.accept_ownership(), Ok(()));
+            assert_eq!(hotpotato.get_owner(), accounts.bob);
+            assert_eq!(hotpotato.get_pending_owner(), None);
+
+            let transferred: OwnershipTransferred = decode_event(recorded_events().last().unwrap());
+            assert_eq!(transferred.previous_owner, accounts.alice);
+            assert_eq!(transferred.new_owner, accounts.bob);
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(
+                hotpotato.set_max_keeper_reward(0),
+                Err(HotPotatoError::OnlyOwner)
+            );
+        }
+
+        #[ink::test]
+        fn owner_settings_bound_new_games() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+
+            assert_eq!(hotpotato.set_player_limits(3, 8), Ok(()));
+            assert_eq!(hotpotato.set_entry_fee_limits(10, 100), Ok(()));
+            assert_eq!(hotpotato.set_max_keeper_reward(500), Ok(()));
+            assert_eq!(
+                hotpotato.set_mode_enabled(GameMode::Elimination, false),
+                Ok(())
+            );
+
+            let config = GameConfig {
+                min_players: 3,
+                max_players: 8,
+                entry_fee: 10,
+                keeper_reward_bps: 500,
+                ..GameConfig::default()
+            };
+            assert_eq!(hotpotato.create_game(config.clone()), Ok(0));
+
+            for (bad, error) in [
+                (
+                    GameConfig {
+                        min_players: 2,
+                        ..config.clone()
+                    },
+                    HotPotatoError::InvalidPlayerLimits,
+                ),
+                (
+                    GameConfig {
+                        entry_fee: 101,
+                        ..config.clone()
+                    },
+                    HotPotatoError::EntryFeeOutOfRange,
+                ),
+                (
+                    GameConfig {
+                        keeper_reward_bps: 501,
+                        ..config.clone()
+                    },
+                    HotPotatoError::InvalidKeeperReward,
+                ),
+                (
+                    GameConfig {
+                        mode: GameMode::Elimination,
+                        ..config.clone()
+                    },
+                    HotPotatoError::ModeDisabled,
+                ),
+            ] {
+                assert_eq!(hotpotato.create_game(bad), Err(error));
+            }
+            assert_eq!(hotpotato.get_next_game_id(), 1);
+        }
+
+        #[ink::test]
+        fn owner_setters_validate_input() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+
+            assert_eq!(
+                hotpotato.set_player_limits(1, 8),
+                Err(HotPotatoError::InvalidPlayerLimits)
+            );
+            assert_eq!(
+                hotpotato.set_player_limits(4, MAX_PLAYERS + 1),
+                Err(HotPotatoError::InvalidPlayerLimits)
+            );
+            assert_eq!(
+                hotpotato.set_entry_fee_limits(2, 1),
+                Err(HotPotatoError::InvalidFeeLimits)
+            );
+            assert_eq!(
+                hotpotato.set_max_keeper_reward(MAX_KEEPER_REWARD_BPS + 1),
+                Err(HotPotatoError::InvalidKeeperReward)
+            );
+            assert_eq!(
+                hotpotato.set_default_deadline(Deadline {
+                    floor: 20,
+                    ..Deadline::constant(10, DeadlineUnit::Blocks)
+                }),
+                Err(HotPotatoError::InvalidDeadline)
+            );
+            assert_eq!(
+                hotpotato.set_default_deadline(Deadline::constant(0, DeadlineUnit::Blocks)),
+                Err(HotPotatoError::InvalidDeadline)
+            );
+            assert_eq!(hotpotato.set_mode_enabled(GameMode::Classic, false), Ok(()));
+            assert_eq!(
+                hotpotato.set_mode_enabled(GameMode::Elimination, false),
+                Ok(())
+            );
+            assert_eq!(
+                hotpotato.set_mode_enabled(GameMode::Teams, false),
+                Err(HotPotatoError::NoModeEnabled)
+            );
+
+            assert_eq!(
+                hotpotato.get_settings(),
+                Settings {
+                    classic_enabled: false,
+                    elimination_enabled: false,
+                    ..Settings::default()
+                }
+            );
+            assert_eq!(
+                hotpotato.get_default_deadline(),
+                Deadline::constant(10, DeadlineUnit::Blocks)
+            );
+        }
+
+        #[ink::test]
+        fn set_default_deadline_applies_to_new_games() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            let before = hotpotato.create_game(GameConfig::default()).unwrap();
+
+            let deadline = Deadline::constant(5_000, DeadlineUnit::Milliseconds);
+            assert_eq!(hotpotato.set_default_deadline(deadline), Ok(()));
+            let after = hotpotato.create_game(GameConfig::default()).unwrap();
+
+            assert_eq!(
+                hotpotato.get_deadline(before),
+                Some(Deadline::constant(10, DeadlineUnit::Blocks))
+            );
+            assert_eq!(hotpotato.get_deadline(after), Some(deadline));
+
+            let events = recorded_events();
+            let changed: ConfigChanged = decode_event(&events[1]);
+            assert_eq!(changed.owner, accounts.alice);
+            assert_eq!(changed.change, ConfigChange::DefaultDeadline(deadline));
+        }
+
+        #[ink::test]
+        fn only_owner_or_guardian_can_pause() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(hotpotato.pause(), Err(HotPotatoError::OnlyGuardian));
+            assert_eq!(
+                hotpotato.set_guardian(Some(accounts.eve)),
+                Err(HotPotatoError::OnlyOwner)
+            );
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(hotpotato.set_guardian(Some(accounts.eve)), Ok(()));
+            assert_eq!(hotpotato.get_guardian(), Some(accounts.eve));
+
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(hotpotato.pause(), Ok(()));
+            assert!(hotpotato.is_paused());
+            assert_eq!(hotpotato.pause(), Err(HotPotatoError::ContractPaused));
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(hotpotato.unpause(), Ok(()));
+            assert!(!hotpotato.is_paused());
+            assert_eq!(hotpotato.unpause(), Err(HotPotatoError::NotPaused));
+        }
+
+        #[ink::test]
+        fn pause_blocks_play_but_not_resolution() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_default_game(&mut hotpotato);
+            let lobby = hotpotato.create_game(GameConfig::default()).unwrap();
+
+            advance_blocks(3);
+            assert_eq!(hotpotato.pause(), Ok(()));
+
+            assert_eq!(
+                hotpotato.create_game(GameConfig::default()),
+                Err(HotPotatoError::ContractPaused)
+            );
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.join_game(lobby, any_commitment(accounts.bob)),
+                Err(HotPotatoError::ContractPaused)
+            );
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::ContractPaused)
+            );
+            assert_eq!(hotpotato.withdraw(), Err(HotPotatoError::NothingToWithdraw));
+
+            // The potato had already burned when play stopped
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert_eq!(hotpotato.get_winner(game_id), Some(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn pause_freezes_deadline_clock() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            advance_blocks(4);
+            assert_eq!(hotpotato.pause(), Ok(()));
+            advance_blocks(20);
+
+            assert_eq!(hotpotato.get_remaining_blocks(game_id, 0), 6);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(false));
+
+            assert_eq!(hotpotato.unpause(), Ok(()));
+            let unpaused: Unpaused = decode_event(recorded_events().last().unwrap());
+            assert_eq!(unpaused.paused_for.blocks, 20);
+            assert_eq!(hotpotato.get_remaining_blocks(game_id, 0), 6);
+
+            advance_blocks(6);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(false));
+            advance_blocks(1);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert_eq!(hotpotato.get_winner(game_id), Some(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn upgrade_is_owner_only() {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            let code_hash = H256::from([7; 32]);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.upgrade(code_hash), Err(HotPotatoError::OnlyOwner));
+            assert_eq!(hotpotato.migrate(), Err(HotPotatoError::OnlyOwner));
+
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(hotpotato.upgrade(code_hash), Ok(()));
+            assert_eq!(ink::env::own_code_hash(), code_hash);
+            let upgraded: Upgraded = decode_event(recorded_events().last().unwrap());
+            assert_eq!(upgraded.code_hash, code_hash);
+        }
+
+        #[ink::test]
+        fn migrate_preserves_state() {
+            let accounts = ink::env::test::default_accounts();
+
+            // Storage written by the first version: a flat game Alice
+            // started at block 3 and handed to Bob, with a 10 block deadline
+            let padded = |address: H160| {
+                let mut account = [0u8; 32];
+                account[..20].copy_from_slice(address.as_ref());
+                AccountId::from(account)
+            };
+            let root = <Hotpotato as ink::storage::traits::StorageKey>::KEY;
+            ink::env::set_contract_storage(
+                &root,
+                &(
+                    Some(padded(accounts.bob)),
+                    3u32,
+                    10u32,
+                    true,
+                    Some(padded(accounts.alice)),
+                ),
+            );
+            advance_blocks(7);
+
+            // The new code decodes the old root cell as it is
+            let mut hotpotato: Hotpotato = ink::env::get_contract_storage(&root).unwrap().unwrap();
+            assert_eq!(hotpotato.get_storage_version(), 0);
+            assert_eq!(
+                hotpotato.get_default_deadline(),
+                Deadline::constant(10, DeadlineUnit::Blocks)
+            );
+
+            // Neither a third party nor the old game's starter can claim it
+            for caller in [accounts.charlie, accounts.alice] {
+                ink::env::test::set_caller(caller);
+                assert_eq!(hotpotato.migrate(), Err(HotPotatoError::OnlyOwner));
+            }
+            assert_eq!(hotpotato.get_storage_version(), 0);
+
+            ink::env::test::set_caller(LEGACY_OWNER);
+            assert_eq!(hotpotato.migrate(), Ok(STORAGE_VERSION));
+            let migrated: Migrated = decode_event(recorded_events().last().unwrap());
+            assert_eq!(migrated.from, 0);
+            assert_eq!(migrated.to, STORAGE_VERSION);
+            assert_eq!(hotpotato.migrate(), Err(HotPotatoError::AlreadyMigrated));
+
+            assert_eq!(hotpotato.legacy, LegacyGame::default());
+            assert_eq!(hotpotato.get_storage_version(), STORAGE_VERSION);
+            assert_eq!(hotpotato.get_owner(), LEGACY_OWNER);
+            assert_eq!(hotpotato.get_active_games(0, 10), vec![0]);
+            let game = hotpotato.get_game(0).unwrap();
+            assert_eq!(game.starter, accounts.alice);
+            assert_eq!(game.status, GameStatus::Active);
+            assert_eq!(game.players, vec![accounts.bob, accounts.alice]);
+            assert_eq!(hotpotato.get_holder(0, 0), Some(accounts.bob));
+            assert_eq!(hotpotato.get_last_passed_at(0, 0), 3);
+            assert_eq!(hotpotato.get_remaining_blocks(0, 0), 6);
+
+            // Play carries on where it left off
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.pass_potato(0, 0, accounts.alice), Ok(()));
+            assert_eq!(hotpotato.get_player_stats(accounts.bob).blocks_held, 4);
+        }
+
+        #[ink::test]
+        fn lobby_emits_events() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_lobby(&mut hotpotato, &[accounts.bob]);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.leave_game(game_id), Ok(()));
+
+            let events = recorded_events();
+            assert_eq!(events.len(), 3);
+
+            let created: GameCreated = decode_event(&events[0]);
+            assert_eq!(created.game_id, game_id);
+            assert_eq!(created.starter, accounts.alice);
+            assert_eq!(created.min_players, MIN_PLAYERS);
+            assert_eq!(created.max_players, MAX_PLAYERS);
+            assert_eq!(created.mode, GameMode::Classic);
+
+            let joined: PlayerJoined = decode_event(&events[1]);
+            assert_eq!(joined.player, accounts.bob);
+
+            let left: PlayerLeft = decode_event(&events[2]);
+            assert_eq!(left.player, accounts.bob);
+        }
+
+        #[ink::test]
+        fn start_game_emits_game_started() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            let events = recorded_events();
+            let started_event = events.last().unwrap();
+            // signature topic + game id + starter + holder
+            assert_eq!(started_event.topics.len(), 4);
+
+            let started: GameStarted = decode_event(started_event);
+            assert_eq!(started.game_id, game_id);
+            assert_eq!(started.starter, accounts.alice);
+            assert_eq!(started.holder, accounts.bob);
+            assert_eq!(started.block, 0);
+            assert_eq!(started.remaining, 10);
+        }
+
+        #[ink::test]
+        fn pass_potato_emits_potato_passed() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+
+            advance_blocks(1);
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.charlie), Ok(()));
+
+            let events = recorded_events();
+            let passed: PotatoPassed = decode_event(events.last().unwrap());
+            assert_eq!(passed.game_id, game_id);
+            assert_eq!(passed.from, accounts.bob);
+            assert_eq!(passed.to, accounts.charlie);
+            assert_eq!(passed.block, 1);
+            assert_eq!(passed.remaining, 9);
+        }
+
+        #[ink::test]
+        fn check_deadline_emits_burn_and_end() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_default_game(&mut hotpotato);
+
+            advance_blocks(3);
+
+            ink::env::test::set_caller(accounts.django);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+
+            let events = recorded_events();
+            let [.., burned_event, ended_event] = &events[..] else {
+                panic!("expected burn and end events");
+            };
+
+            let burned: PotatoBurned = decode_event(burned_event);
+            assert_eq!(burned.game_id, game_id);
+            assert_eq!(burned.potato, Some(0));
+            assert_eq!(burned.holder, accounts.bob);
+            assert_eq!(burned.block, 3);
+
+            let ended: GameEnded = decode_event(ended_event);
+            assert_eq!(ended.game_id, game_id);
+            assert_eq!(ended.ended_by, accounts.django);
+            assert_eq!(ended.holder, Some(accounts.bob));
+            assert_eq!(ended.block, 3);
+            assert_eq!(ended.remaining, 0);
+            assert_eq!(ended.winner, Some(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn end_game_emits_game_ended() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = start_default_game(&mut hotpotato);
+            advance_blocks(1);
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+
+            let events = recorded_events();
+            let ended: GameEnded = decode_event(events.last().unwrap());
+            assert_eq!(ended.game_id, game_id);
+            assert_eq!(ended.ended_by, accounts.alice);
+            assert_eq!(ended.holder, Some(accounts.bob));
+            assert_eq!(ended.block, 1);
+            assert_eq!(ended.remaining, 9);
+            assert_eq!(ended.winner, None);
+        }
+
+        #[ink::test]
+        fn address_of_eth_derived_account_strips_suffix() {
+            let hotpotato = Hotpotato::new(10);
+
+            let mut account = [0xEEu8; 32];
+            account[..20].copy_from_slice(&[0x11; 20]);
+
+            assert_eq!(
+                hotpotato.address_of(AccountId::from(account)),
+                H160::from([0x11; 20])
+            );
+        }
+
+        #[ink::test]
+        fn address_of_native_account_hashes() {
+            let hotpotato = Hotpotato::new(10);
+
+            let account = AccountId::from([0x11; 32]);
+            let address = hotpotato.address_of(account);
+
+            // Not a plain truncation and stable across calls
+            assert_ne!(address, H160::from([0x11; 20]));
+            assert_eq!(address, hotpotato.address_of(account));
+        }
+
+        #[ink::test]
+        fn join_requires_exact_entry_fee() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+
+            let config = GameConfig {
+                entry_fee: 100,
+                ..GameConfig::default()
+            };
+            let game_id = open_lobby_with(&mut hotpotato, config, &[]);
+
+            let commitment = any_commitment(accounts.bob);
+            for value in [0, 99, 101] {
+                assert_eq!(
+                    join_paying(&mut hotpotato, game_id, accounts.bob, value, commitment),
+                    Err(HotPotatoError::IncorrectEntryFee)
+                );
+            }
+            assert_eq!(
+                join_paying(&mut hotpotato, game_id, accounts.bob, 100, commitment),
+                Ok(())
+            );
+            assert_eq!(hotpotato.get_pool(game_id), 100);
+        }
+
+        #[ink::test]
+        fn leave_credits_entry_fee() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_paid_lobby(&mut hotpotato, GameMode::Classic);
+            assert_eq!(hotpotato.get_pool(game_id), 300);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.leave_game(game_id), Ok(()));
+
+            assert_eq!(hotpotato.claimable_of(accounts.bob), 100);
+            assert_eq!(hotpotato.get_pool(game_id), 200);
+        }
+
+        #[ink::test]
+        fn cancelled_lobby_refunds_everyone() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_paid_lobby(&mut hotpotato, GameMode::Classic);
+
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+
+            for player in [accounts.bob, accounts.charlie, accounts.django] {
+                assert_eq!(hotpotato.claimable_of(player), 100);
             }
+            assert_eq!(hotpotato.get_pool(game_id), 0);
         }
 
-        /// Helper function to convert caller to AccountId
-        fn caller_to_account_id(&self) -> AccountId {
-            // For simplicity, we'll create a deterministic AccountId from the caller
-            // In a real contract, you might want a more sophisticated conversion
-            let caller = self.env().caller();
-            let mut account_id = [0u8; 32];
-            // Copy the 20 bytes from caller to the first 20 bytes of AccountId
-            account_id[..20].copy_from_slice(caller.as_ref());
-            AccountId::from(account_id)
+        #[ink::test]
+        fn burn_splits_pool_between_survivors() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = open_paid_lobby(&mut hotpotato, GameMode::Classic);
+            assert_eq!(launch(&mut hotpotato, game_id), Ok(()));
+
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+
+            // Bob burned and forfeits his stake
+            assert_eq!(hotpotato.claimable_of(accounts.bob), 0);
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 150);
+            assert_eq!(hotpotato.claimable_of(accounts.django), 150);
+            assert_eq!(hotpotato.get_pool(game_id), 0);
+
+            let events = recorded_events();
+            let credited: WinningsCredited = decode_event(&events[events.len() - 3]);
+            assert_eq!(credited.game_id, game_id);
+            assert_eq!(credited.player, accounts.charlie);
+            assert_eq!(credited.amount, 150);
         }
 
-        /// 🚀 Start a new hot potato game
-        /// This demonstrates event emission in ink!
-        #[ink(message)]
-        pub fn start_game(&mut self, to: AccountId) {
-            assert!(!self.active, "Game already active");
+        #[ink::test]
+        fn pool_remainder_goes_to_first_survivor() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
 
-            let caller = self.caller_to_account_id();
-            let current_block = self.env().block_number();
+            let config = GameConfig {
+                entry_fee: 5,
+                ..GameConfig::default()
+            };
+            let game_id = open_lobby_with(&mut hotpotato, config, &[]);
+            let stayers = [
+                accounts.charlie,
+                accounts.django,
+                accounts.eve,
+                accounts.frank,
+            ];
+            let commitment = |player| commitment_for(game_id, &stayers, player);
+            let bob_commitment = any_commitment(accounts.bob);
+            join_paying(&mut hotpotato, game_id, accounts.bob, 5, bob_commitment).unwrap();
+            for player in [accounts.charlie, accounts.django] {
+                join_paying(&mut hotpotato, game_id, player, 5, commitment(player)).unwrap();
+            }
 
-            // Update contract state
-            self.current_holder = Some(to);
-            self.last_passed_block = current_block;
-            self.active = true;
-            self.game_starter = Some(caller);
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.leave_game(game_id), Ok(()));
+            for player in [accounts.eve, accounts.frank] {
+                join_paying(&mut hotpotato, game_id, player, 5, commitment(player)).unwrap();
+            }
+
+            // Charlie, now seated first, draws the potato
+            assert_eq!(launch(&mut hotpotato, game_id), Ok(()));
 
-            // 🎯 EMIT EVENT - Commented out for now
-            // self.env().emit_event(GameAction {
-            //     action_type: 1, // start
-            //     player: caller,
-            // });
+            // Charlie burns, 20 is split between Django, Eve and Frank
+            advance_blocks(11);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert_eq!(hotpotato.claimable_of(accounts.django), 8);
+            assert_eq!(hotpotato.claimable_of(accounts.eve), 6);
+            assert_eq!(hotpotato.claimable_of(accounts.frank), 6);
         }
 
-        /// 🔄 Pass the potato to another account
-        /// Shows conditional event emission
-        #[ink(message)]
-        pub fn pass_potato(&mut self, to: AccountId) {
-            assert!(self.active, "Game not active");
+        #[ink::test]
+        fn elimination_winner_takes_pool() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = open_paid_lobby(&mut hotpotato, GameMode::Elimination);
+            assert_eq!(launch(&mut hotpotato, game_id), Ok(()));
 
-            let caller = self.caller_to_account_id();
-            assert_eq!(self.current_holder, Some(caller), "Not current holder");
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            // Nothing is credited while rounds are still being played
+            assert_eq!(hotpotato.get_pool(game_id), 300);
+            assert_eq!(hotpotato.claimable_of(accounts.django), 0);
 
-            let current_block = self.env().block_number();
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
 
-            // Check deadline
-            assert!(
-                current_block <= self.last_passed_block + self.deadline_blocks,
-                "Deadline passed"
-            );
+            assert_eq!(hotpotato.get_winner(game_id), Some(accounts.django));
+            assert_eq!(hotpotato.claimable_of(accounts.django), 300);
+        }
+
+        #[ink::test]
+        fn withdraw_transfers_claimable_balance() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let game_id = open_paid_lobby(&mut hotpotato, GameMode::Classic);
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
 
-            // Calculate remaining blocks
-            let _remaining_blocks = (self.last_passed_block + self.deadline_blocks)
-                .saturating_sub(current_block);
+            let before = balance_of(accounts.charlie);
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(hotpotato.withdraw(), Ok(100));
 
-            // Update state
-            self.current_holder = Some(to);
-            self.last_passed_block = current_block;
+            assert_eq!(balance_of(accounts.charlie), before + U256::from(100));
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 0);
+            assert_eq!(hotpotato.withdraw(), Err(HotPotatoError::NothingToWithdraw));
 
-            // 🎯 EMIT EVENT - Commented out for now
-            // self.env().emit_event(GameAction {
-            //     action_type: 2, // pass
-            //     player: caller,
-            // });
+            let events = recorded_events();
+            let withdrawn: Withdrawn = decode_event(events.last().unwrap());
+            assert_eq!(withdrawn.player, accounts.charlie);
+            assert_eq!(withdrawn.amount, 100);
         }
 
-        /// 💥 Check deadline and burn potato if expired
-        /// Demonstrates multiple event emissions in one function
-        #[ink(message)]
-        pub fn check_deadline(&mut self) -> bool {
-            if !self.active {
-                return false;
+        #[ink::test]
+        fn failed_withdraw_keeps_balance() {
+            let accounts = ink::env::test::default_accounts();
+            // A contract account that was never funded, so every transfer
+            // out of it fails
+            let contract = H160::from([0xC0; 20]);
+            ink::env::test::set_callee(contract);
+            ink::env::test::set_caller(accounts.alice);
+            let mut hotpotato = Hotpotato::new(10);
+            let config = GameConfig {
+                entry_fee: 100,
+                ..GameConfig::default()
+            };
+            let game_id = hotpotato.create_game(config).unwrap();
+            let players = [accounts.bob, accounts.charlie, accounts.django];
+            for player in players {
+                ink::env::test::set_caller(player);
+                ink::env::test::set_value_transferred(U256::from(100));
+                let commitment = commitment_for(game_id, &players, player);
+                assert_eq!(hotpotato.join_game(game_id, commitment), Ok(()));
             }
+            ink::env::test::set_value_transferred(U256::zero());
+            assert_eq!(launch(&mut hotpotato, game_id), Ok(()));
 
-            let current_block = self.env().block_number();
+            // The game still ends because resolution only credits the ledger
+            assert_eq!(hotpotato.end_game(game_id), Ok(()));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
 
-            if current_block > self.last_passed_block + self.deadline_blocks {
-                let _last_holder = self.current_holder.unwrap();
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(hotpotato.withdraw(), Err(HotPotatoError::PayoutFailed));
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 100);
 
-                // 🎯 EMIT EVENT - Commented out for now
-                // self.env().emit_event(GameAction {
-                //     action_type: 3, // end
-                //     player: last_holder,
-                // });
+            ink::env::test::set_contract_balance(contract, U256::from(300));
+            assert_eq!(hotpotato.withdraw(), Ok(100));
+        }
 
-                // Reset game
-                self.reset_game();
-                return true;
-            }
+        /// A 300 pool classic game paying 5% to keepers, Bob holding
+        fn start_keeper_game(hotpotato: &mut Hotpotato) -> GameId {
+            let config = GameConfig {
+                entry_fee: 100,
+                keeper_reward_bps: 500,
+                ..GameConfig::default()
+            };
+            let game_id = open_paid_lobby_with(hotpotato, config);
+            launch(hotpotato, game_id).unwrap();
+            game_id
+        }
 
-            false
+        #[ink::test]
+        fn create_game_invalid_keeper_reward_fails() {
+            let mut hotpotato = Hotpotato::new(10);
+            let config = GameConfig {
+                keeper_reward_bps: MAX_KEEPER_REWARD_BPS + 1,
+                ..GameConfig::default()
+            };
+            assert_eq!(
+                hotpotato.create_game(config),
+                Err(HotPotatoError::InvalidKeeperReward)
+            );
         }
 
-        /// 🏆 End game manually (only by starter)
-        #[ink(message)]
-        pub fn end_game(&mut self) {
-            assert!(self.active, "Game not active");
+        #[ink::test]
+        fn keeper_rewarded_for_burn() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_keeper_game(&mut hotpotato);
 
-            let caller = self.caller_to_account_id();
-            assert_eq!(self.game_starter, Some(caller), "Only starter can end");
+            advance_blocks(3);
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
 
-            let _current_block = self.env().block_number();
-            let _has_winner = self.current_holder.is_some();
+            // 5% of 300 to Eve, the rest split between the survivors
+            assert_eq!(hotpotato.claimable_of(accounts.eve), 15);
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 143);
+            assert_eq!(hotpotato.claimable_of(accounts.django), 142);
 
-            // 🎯 EMIT EVENT - Commented out for now
-            // self.env().emit_event(GameAction {
-            //     action_type: 3, // end
-            //     player: caller,
-            // });
+            // burn, keeper credit, reward, two survivor credits, end
+            let events = recorded_events();
+            let rewarded: KeeperRewarded = decode_event(&events[events.len() - 4]);
+            assert_eq!(rewarded.game_id, game_id);
+            assert_eq!(rewarded.keeper, accounts.eve);
+            assert_eq!(rewarded.amount, 15);
 
-            self.reset_game();
+            // The burn is settled, a second call cannot claim again
+            assert_eq!(
+                hotpotato.check_deadline(game_id),
+                Err(HotPotatoError::GameNotActive)
+            );
+            assert_eq!(hotpotato.claimable_of(accounts.eve), 15);
         }
 
-        /// Helper function to reset game state
-        fn reset_game(&mut self) {
-            self.current_holder = None;
-            self.active = false;
-            self.game_starter = None;
+        #[ink::test]
+        fn keeper_not_rewarded_before_expiry() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_keeper_game(&mut hotpotato);
+
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(false));
+            assert_eq!(hotpotato.claimable_of(accounts.eve), 0);
+            assert_eq!(hotpotato.get_pool(game_id), 300);
         }
 
-        /// 📖 Query functions (no events needed for reads)
+        #[ink::test]
+        fn burned_holder_gets_no_keeper_reward() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_keeper_game(&mut hotpotato);
 
-        #[ink(message)]
-        pub fn get_holder(&self) -> Option<AccountId> {
-            self.current_holder
+            advance_blocks(3);
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+
+            assert_eq!(hotpotato.claimable_of(accounts.bob), 0);
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 150);
+            assert_eq!(hotpotato.claimable_of(accounts.django), 150);
         }
 
-        #[ink(message)]
-        pub fn is_active(&self) -> bool {
-            self.active
+        fn set_timestamp(millis: u64) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(millis);
         }
 
-        #[ink(message)]
-        pub fn get_deadline_blocks(&self) -> u32 {
-            self.deadline_blocks
+        #[ink::test]
+        fn timestamp_deadline_burns_after_expiry() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new_with_unit(1_000, DeadlineUnit::Milliseconds);
+            set_timestamp(5_000);
+            let game_id = start_default_game(&mut hotpotato);
+            assert_eq!(hotpotato.get_last_passed_at(game_id, 0), 5_000);
+
+            // Blocks alone do not move a millisecond deadline
+            advance_blocks(50);
+            set_timestamp(6_000);
+            assert_eq!(hotpotato.get_remaining_blocks(game_id, 0), 0);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(false));
+
+            set_timestamp(6_001);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert_eq!(hotpotato.get_winner(game_id), Some(accounts.charlie));
         }
 
-        #[ink(message)]
-        pub fn get_last_passed_block(&self) -> u32 {
-            self.last_passed_block
+        #[ink::test]
+        fn timestamp_pass_reports_remaining_millis() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new_with_unit(1_000, DeadlineUnit::Milliseconds);
+            set_timestamp(5_000);
+            let game_id = start_default_game(&mut hotpotato);
+
+            set_timestamp(5_400);
+            assert_eq!(hotpotato.get_remaining_blocks(game_id, 0), 600);
+
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(hotpotato.pass_potato(game_id, 0, accounts.charlie), Ok(()));
+            let passed: PotatoPassed = decode_event(recorded_events().last().unwrap());
+            assert_eq!(passed.remaining, 600);
+            assert_eq!(hotpotato.get_last_passed_at(game_id, 0), 5_400);
+            assert_eq!(hotpotato.get_remaining_blocks(game_id, 0), 1_000);
+
+            set_timestamp(6_401);
+            ink::env::test::set_caller(accounts.charlie);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.bob),
+                Err(HotPotatoError::DeadlinePassed)
+            );
         }
 
-        #[ink(message)]
-        pub fn get_game_starter(&self) -> Option<AccountId> {
-            self.game_starter
+        #[ink::test]
+        fn game_deadline_overrides_default() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(10);
+            let deadline = Deadline::constant(500, DeadlineUnit::Milliseconds);
+            let config = GameConfig {
+                deadline: Some(deadline),
+                ..GameConfig::default()
+            };
+            let timed = open_lobby_with(&mut hotpotato, config, &[accounts.bob, accounts.charlie]);
+            let blocks = open_lobby(&mut hotpotato, &[accounts.bob, accounts.charlie]);
+            assert_eq!(hotpotato.get_deadline(timed), Some(deadline));
+            assert_eq!(
+                hotpotato.get_deadline(blocks).map(|deadline| deadline.unit),
+                Some(DeadlineUnit::Blocks)
+            );
+
+            set_timestamp(1_000);
+            launch(&mut hotpotato, timed).unwrap();
+            launch(&mut hotpotato, blocks).unwrap();
+            let started: GameStarted = decode_event(recorded_events().last().unwrap());
+            assert_eq!(started.remaining, 10);
+
+            // A timestamp jump only expires the millisecond game
+            set_timestamp(2_000);
+            assert_eq!(hotpotato.check_deadline(blocks), Ok(false));
+            assert_eq!(hotpotato.check_deadline(timed), Ok(true));
         }
 
-        #[ink(message)]
-        pub fn get_remaining_blocks(&self) -> u32 {
-            if !self.active {
-                return 0;
-            }
-            let current_block = self.env().block_number();
-            (self.last_passed_block + self.deadline_blocks).saturating_sub(current_block)
+        /// Alice opens a game for Bob and Charlie with `deadline` and starts
+        /// it with Bob holding
+        fn start_curve_game(hotpotato: &mut Hotpotato, deadline: Deadline) -> GameId {
+            let accounts = ink::env::test::default_accounts();
+            let config = GameConfig {
+                deadline: Some(deadline),
+                ..GameConfig::default()
+            };
+            let game_id = open_lobby_with(hotpotato, config, &[accounts.bob, accounts.charlie]);
+            launch(hotpotato, game_id).unwrap();
+            game_id
         }
-    }
 
-    /// 🧪 Unit Tests - Testing Events and Game Logic
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+        /// Alice opens a team game with a 100 entry fee; Bob and Charlie
+        /// join team 0, Django and Eve team 1
+        fn open_team_lobby(hotpotato: &mut Hotpotato, teams: TeamConfig) -> GameId {
+            let accounts = ink::env::test::default_accounts();
+            ink::env::test::set_caller(accounts.alice);
+            let config = GameConfig {
+                mode: GameMode::Teams,
+                entry_fee: 100,
+                ..GameConfig::default()
+            };
+            let game_id = hotpotato.create_team_game(config, teams).unwrap();
+            let players = [
+                accounts.bob,
+                accounts.charlie,
+                accounts.django,
+                accounts.eve,
+            ];
+            for (seat, player) in players.iter().enumerate() {
+                ink::env::test::set_caller(*player);
+                ink::env::test::set_value_transferred(U256::from(100));
+                let commitment = commitment_for(game_id, &players, *player);
+                hotpotato
+                    .join_team(game_id, seat as u32 / 2, commitment)
+                    .unwrap();
+            }
+            ink::env::test::set_value_transferred(U256::zero());
+            let contract = contract_address();
+            ink::env::test::set_contract_balance(contract, balance_of(contract) + U256::from(400));
+            ink::env::test::set_caller(accounts.alice);
+            game_id
+        }
 
-        #[ink::test]
-        fn new_works() {
-            let hotpotato = Hotpotato::new(10);
-            assert_eq!(hotpotato.get_deadline_blocks(), 10);
-            assert!(!hotpotato.is_active());
-            assert_eq!(hotpotato.get_holder(), None);
+        /// Pass the potato back and forth between Bob and Charlie `passes`
+        /// times, starting with whoever holds it
+        fn pass_back_and_forth(hotpotato: &mut Hotpotato, game_id: GameId, passes: u32) {
+            let accounts = ink::env::test::default_accounts();
+            for _ in 0..passes {
+                let (from, to) = if hotpotato.get_holder(game_id, 0) == Some(accounts.bob) {
+                    (accounts.bob, accounts.charlie)
+                } else {
+                    (accounts.charlie, accounts.bob)
+                };
+                ink::env::test::set_caller(from);
+                hotpotato.pass_potato(game_id, 0, to).unwrap();
+            }
         }
 
         #[ink::test]
-        fn start_game_works() {
-            let accounts = ink::env::test::default_accounts();
+        fn linear_deadline_shrinks_to_floor() {
             let mut hotpotato = Hotpotato::new(10);
+            let deadline = Deadline {
+                window: 10,
+                unit: DeadlineUnit::Blocks,
+                curve: DeadlineCurve::Linear { step: 3 },
+                floor: 2,
+            };
+            let game_id = start_curve_game(&mut hotpotato, deadline);
+            assert_eq!(hotpotato.get_current_window(game_id, 0), 10);
 
-            // Create simple test AccountIds
-            let alice = AccountId::from([0x01; 32]);
-            let bob = AccountId::from([0x02; 32]);
+            pass_back_and_forth(&mut hotpotato, game_id, 1);
+            assert_eq!(hotpotato.get_current_window(game_id, 0), 7);
+            let passed: PotatoPassed = decode_event(recorded_events().last().unwrap());
+            assert_eq!(passed.window, 7);
 
-            // Set caller to alice (using H160 for test environment)
-            ink::env::test::set_caller(accounts.alice);
+            pass_back_and_forth(&mut hotpotato, game_id, 1);
+            assert_eq!(hotpotato.get_current_window(game_id, 0), 4);
+            pass_back_and_forth(&mut hotpotato, game_id, 2);
+            assert_eq!(hotpotato.get_current_window(game_id, 0), 2);
+            assert_eq!(hotpotato.get_remaining_blocks(game_id, 0), 2);
+
+            // The shrunken window is the one enforced
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+        }
 
-            // Start game - should not panic
-            hotpotato.start_game(bob);
+        #[ink::test]
+        fn geometric_deadline_decays_to_floor() {
+            let mut hotpotato = Hotpotato::new(10);
+            let deadline = Deadline {
+                window: 100,
+                unit: DeadlineUnit::Blocks,
+                curve: DeadlineCurve::Geometric { ratio_bps: 5_000 },
+                floor: 20,
+            };
+            let game_id = start_curve_game(&mut hotpotato, deadline);
 
-            // Verify state
-            assert!(hotpotato.is_active());
-            assert_eq!(hotpotato.get_holder(), Some(bob));
-            assert_eq!(hotpotato.get_game_starter(), Some(alice));
+            let mut windows = Vec::new();
+            for _ in 0..4 {
+                pass_back_and_forth(&mut hotpotato, game_id, 1);
+                windows.push(hotpotato.get_current_window(game_id, 0));
+            }
+            assert_eq!(windows, vec![50, 25, 20, 20]);
         }
 
         #[ink::test]
-        #[should_panic(expected = "Game already active")]
-        fn start_game_twice_panics() {
+        fn elimination_round_restores_full_window() {
             let accounts = ink::env::test::default_accounts();
             let mut hotpotato = Hotpotato::new(10);
+            let config = GameConfig {
+                mode: GameMode::Elimination,
+                deadline: Some(Deadline {
+                    window: 6,
+                    unit: DeadlineUnit::Blocks,
+                    curve: DeadlineCurve::Linear { step: 2 },
+                    floor: 2,
+                }),
+                ..GameConfig::default()
+            };
+            let game_id = open_lobby_with(
+                &mut hotpotato,
+                config,
+                &[accounts.bob, accounts.charlie, accounts.django],
+            );
+            launch(&mut hotpotato, game_id).unwrap();
+            pass_back_and_forth(&mut hotpotato, game_id, 2);
+            assert_eq!(hotpotato.get_current_window(game_id, 0), 2);
+
+            advance_blocks(3);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
+            assert_eq!(hotpotato.get_round(game_id), 2);
+            assert_eq!(hotpotato.get_current_window(game_id, 0), 6);
+        }
+
+        #[ink::test]
+        fn create_game_invalid_deadline_fails() {
+            let mut hotpotato = Hotpotato::new(10);
+            for deadline in [
+                Deadline::constant(0, DeadlineUnit::Milliseconds),
+                Deadline {
+                    floor: 11,
+                    ..Deadline::constant(10, DeadlineUnit::Blocks)
+                },
+                Deadline {
+                    curve: DeadlineCurve::Geometric { ratio_bps: 10_001 },
+                    ..Deadline::constant(10, DeadlineUnit::Blocks)
+                },
+            ] {
+                let config = GameConfig {
+                    deadline: Some(deadline),
+                    ..GameConfig::default()
+                };
+                assert_eq!(
+                    hotpotato.create_game(config),
+                    Err(HotPotatoError::InvalidDeadline)
+                );
+            }
+            assert_eq!(hotpotato.get_current_window(0, 0), 0);
+        }
+
+        const FUSE_SECRET: [u8; 32] = [7; 32];
 
-            let bob = AccountId::from([0x02; 32]);
-            let charlie = AccountId::from([0x03; 32]);
+        fn fuse_config(min_fuse: u64, max_fuse: u64) -> GameConfig {
+            GameConfig {
+                entry_fee: 100,
+                fuse: Some(FuseConfig {
+                    min_fuse,
+                    max_fuse,
+                    reveal_window: 10,
+                    bond: 50,
+                }),
+                ..GameConfig::default()
+            }
+        }
 
+        /// Alice closes `game_id` with `commitment`, attaching `bond`
+        fn close_paying(
+            hotpotato: &mut Hotpotato,
+            game_id: GameId,
+            commitment: Option<Hash>,
+            bond: Balance,
+        ) -> Result<()> {
+            let accounts = ink::env::test::default_accounts();
             ink::env::test::set_caller(accounts.alice);
+            ink::env::test::set_value_transferred(U256::from(bond));
+            let result = hotpotato.close_lobby(game_id, commitment);
+            if result.is_ok() {
+                let contract = contract_address();
+                ink::env::test::set_contract_balance(
+                    contract,
+                    balance_of(contract) + U256::from(bond),
+                );
+            }
+            ink::env::test::set_value_transferred(U256::zero());
+            result
+        }
 
-            hotpotato.start_game(bob);
-            hotpotato.start_game(charlie); // Should panic
+        /// Bob, Charlie and Django pay into Alice's hidden fuse game, which
+        /// starts with Bob holding and a fuse drawn between `min_fuse` and
+        /// `max_fuse` blocks
+        fn start_fuse_game(hotpotato: &mut Hotpotato, min_fuse: u64, max_fuse: u64) -> GameId {
+            let game_id = open_paid_lobby_with(hotpotato, fuse_config(min_fuse, max_fuse));
+            let commitment = fuse_commitment(&Hash::from(FUSE_SECRET));
+            close_paying(hotpotato, game_id, Some(commitment), 50).unwrap();
+            reveal_all(hotpotato, game_id);
+            hotpotato.start_game(game_id).unwrap();
+            game_id
         }
 
         #[ink::test]
-        fn pass_potato_works() {
+        fn fuse_valid_reveal_burns_holder_at_expiry() {
             let accounts = ink::env::test::default_accounts();
-            let mut hotpotato = Hotpotato::new(10);
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_fuse_game(&mut hotpotato, 8, 8);
+            let secret = Hash::from(FUSE_SECRET);
+
+            // Holders are not bound by the regular deadline
+            advance_blocks(3);
+            ink::env::test::set_caller(accounts.bob);
+            hotpotato.pass_potato(game_id, 0, accounts.charlie).unwrap();
+            ink::env::test::set_caller(accounts.alice);
+            assert_eq!(
+                hotpotato.reveal_fuse(game_id, secret),
+                Err(HotPotatoError::FuseNotExpired)
+            );
 
-            let bob = AccountId::from([0x02; 32]);
-            let charlie = AccountId::from([0x03; 32]);
+            advance_blocks(3);
+            ink::env::test::set_caller(accounts.charlie);
+            hotpotato.pass_potato(game_id, 0, accounts.django).unwrap();
+            advance_blocks(3);
+            ink::env::test::set_caller(accounts.django);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.bob),
+                Err(HotPotatoError::DeadlinePassed)
+            );
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(false));
 
-            // Alice starts game with Bob as holder
+            // The fuse burned down while Django held the potato
             ink::env::test::set_caller(accounts.alice);
-            hotpotato.start_game(bob);
+            assert_eq!(hotpotato.reveal_fuse(game_id, secret), Ok(()));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
+            assert_eq!(
+                hotpotato.get_elimination_order(game_id),
+                vec![accounts.django]
+            );
 
-            // Bob passes to Charlie
-            ink::env::test::set_caller(accounts.bob);
-            hotpotato.pass_potato(charlie);
+            let events = recorded_events();
+            let revealed: FuseRevealed = decode_event(&events[events.len() - 5]);
+            assert_eq!(revealed.holder, accounts.django);
+            assert_eq!(revealed.fuse, 8);
+
+            assert_eq!(hotpotato.claimable_of(accounts.alice), 50);
+            assert_eq!(hotpotato.claimable_of(accounts.bob), 150);
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 150);
+            assert_eq!(hotpotato.claimable_of(accounts.django), 0);
+        }
+
+        #[ink::test]
+        fn fuse_length_is_drawn_from_seed() {
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_fuse_game(&mut hotpotato, 5, 20);
+            let secret = Hash::from(FUSE_SECRET);
+
+            let seed = hotpotato.get_game(game_id).unwrap().beacon.seed.unwrap();
+            let bounds = fuse_config(5, 20).fuse.unwrap();
+            let fuse = bounds.length_for(&secret, &seed);
+            assert!((5..=20).contains(&fuse));
+
+            advance_blocks(fuse as u32);
+            assert_eq!(
+                hotpotato.reveal_fuse(game_id, secret),
+                Err(HotPotatoError::FuseNotExpired)
+            );
+            advance_blocks(1);
+            assert_eq!(hotpotato.reveal_fuse(game_id, secret), Ok(()));
 
-            assert_eq!(hotpotato.get_holder(), Some(charlie));
+            let events = recorded_events();
+            let revealed: FuseRevealed = decode_event(&events[events.len() - 5]);
+            assert_eq!(revealed.fuse, fuse);
         }
 
         #[ink::test]
-        #[should_panic(expected = "Not current holder")]
-        fn pass_potato_wrong_holder_panics() {
+        fn fuse_invalid_reveal_fails() {
             let accounts = ink::env::test::default_accounts();
-            let mut hotpotato = Hotpotato::new(10);
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_fuse_game(&mut hotpotato, 8, 8);
+            advance_blocks(9);
+
+            assert_eq!(
+                hotpotato.reveal_fuse(game_id, Hash::from([8; 32])),
+                Err(HotPotatoError::InvalidFuseReveal)
+            );
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.reveal_fuse(game_id, Hash::from(FUSE_SECRET)),
+                Err(HotPotatoError::OnlyStarter)
+            );
+            assert!(hotpotato.is_active(game_id));
+        }
+
+        #[ink::test]
+        fn fuse_missing_reveal_slashes_bond() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = start_fuse_game(&mut hotpotato, 8, 8);
 
-            let alice = AccountId::from([0x01; 32]);
-            let bob = AccountId::from([0x02; 32]);
+            // Nobody can pass once the longest fuse has burned down
+            advance_blocks(9);
+            ink::env::test::set_caller(accounts.bob);
+            assert_eq!(
+                hotpotato.pass_potato(game_id, 0, accounts.charlie),
+                Err(HotPotatoError::DeadlinePassed)
+            );
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(false));
 
-            // Alice starts, Bob is holder
+            advance_blocks(10);
             ink::env::test::set_caller(accounts.alice);
-            hotpotato.start_game(bob);
+            assert_eq!(
+                hotpotato.reveal_fuse(game_id, Hash::from(FUSE_SECRET)),
+                Err(HotPotatoError::RevealWindowClosed)
+            );
+            ink::env::test::set_caller(accounts.eve);
+            assert_eq!(hotpotato.check_deadline(game_id), Ok(true));
 
-            // Charlie tries to pass (but he's not holder)
-            ink::env::test::set_caller(accounts.charlie);
-            hotpotato.pass_potato(alice); // Should panic
+            let events = recorded_events();
+            let slashed: FuseSlashed = decode_event(&events[events.len() - 5]);
+            assert_eq!(slashed.starter, accounts.alice);
+            assert_eq!(slashed.bond, 50);
+
+            // Nobody was burned, so the bond and the fees go to everyone
+            assert_eq!(hotpotato.get_elimination_order(game_id), Vec::<H160>::new());
+            assert_eq!(hotpotato.claimable_of(accounts.alice), 0);
+            assert_eq!(hotpotato.claimable_of(accounts.bob), 118);
+            assert_eq!(hotpotato.claimable_of(accounts.charlie), 116);
+            assert_eq!(hotpotato.claimable_of(accounts.django), 116);
         }
 
         #[ink::test]
-        fn end_game_works() {
+        fn fuse_close_checks_commitment_and_bond() {
             let accounts = ink::env::test::default_accounts();
-            let mut hotpotato = Hotpotato::new(10);
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = open_paid_lobby_with(&mut hotpotato, fuse_config(5, 20));
+            let commitment = fuse_commitment(&Hash::from(FUSE_SECRET));
 
-            let bob = AccountId::from([0x02; 32]);
+            assert_eq!(
+                close_paying(&mut hotpotato, game_id, None, 50),
+                Err(HotPotatoError::FuseCommitmentRequired)
+            );
+            assert_eq!(
+                close_paying(&mut hotpotato, game_id, Some(commitment), 49),
+                Err(HotPotatoError::IncorrectFuseBond)
+            );
 
-            // Alice starts and ends game
-            ink::env::test::set_caller(accounts.alice);
-            hotpotato.start_game(bob);
-            hotpotato.end_game();
+            let plain = open_lobby(&mut hotpotato, &[accounts.bob, accounts.charlie]);
+            assert_eq!(
+                close_paying(&mut hotpotato, plain, Some(commitment), 0),
+                Err(HotPotatoError::NoHiddenFuse)
+            );
+            assert_eq!(
+                hotpotato.reveal_fuse(plain, Hash::from(FUSE_SECRET)),
+                Err(HotPotatoError::GameNotActive)
+            );
+        }
 
-            assert!(!hotpotato.is_active());
-            assert_eq!(hotpotato.get_holder(), None);
+        #[ink::test]
+        fn fuse_bond_returned_when_nobody_reveals_entropy() {
+            let accounts = ink::env::test::default_accounts();
+            let mut hotpotato = Hotpotato::new(2);
+            let game_id = open_paid_lobby_with(&mut hotpotato, fuse_config(5, 20));
+            let commitment = fuse_commitment(&Hash::from(FUSE_SECRET));
+            close_paying(&mut hotpotato, game_id, Some(commitment), 50).unwrap();
+
+            advance_blocks(3);
+            assert_eq!(hotpotato.start_game(game_id), Ok(()));
+            assert_eq!(hotpotato.get_status(game_id), Some(GameStatus::Ended));
+            assert_eq!(hotpotato.claimable_of(accounts.alice), 50);
+            assert_eq!(hotpotato.claimable_of(accounts.bob), 100);
         }
 
         #[ink::test]
-        fn check_deadline_no_active_game() {
-            let mut hotpotato = Hotpotato::new(10);
-            assert!(!hotpotato.check_deadline());
+        fn create_game_invalid_fuse_fails() {
+            let mut hotpotato = Hotpotato::new(2);
+            let bounds = fuse_config(5, 20).fuse.unwrap();
+            for config in [
+                GameConfig {
+                    mode: GameMode::Elimination,
+                    ..fuse_config(5, 20)
+                },
+                GameConfig {
+                    fuse: Some(FuseConfig {
+                        min_fuse: 21,
+                        ..bounds
+                    }),
+                    ..fuse_config(5, 20)
+                },
+            ] {
+                assert_eq!(
+                    hotpotato.create_game(config),
+                    Err(HotPotatoError::InvalidFuse)
+                );
+            }
         }
     }
 
-
     /// End-to-end tests for the Hot Potato Game Contract
     ///
     /// When running these you need to make sure that you:
@@ -329,6 +6981,17 @@ mod hotpotato {
 
         type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+        /// Every `T` event among the contract events of a call
+        fn decode_events<T: ink::env::Event + scale::Decode>(
+            events: Vec<ink_e2e::events::EventWithTopics<ink_e2e::events::ContractEmitted>>,
+        ) -> Vec<T> {
+            events
+                .into_iter()
+                .filter(|event| event.topics.first().map(|topic| topic.0) == T::SIGNATURE_TOPIC)
+                .map(|event| T::decode(&mut &event.event.data[..]).expect("undecodable event"))
+                .collect()
+        }
+
         #[ink_e2e::test]
         async fn new_works(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
             // Given
@@ -343,19 +7006,25 @@ mod hotpotato {
             let call_builder = contract.call_builder::<Hotpotato>();
 
             // Then
-            let is_active = call_builder.is_active();
-            let is_active_result = client.call(&ink_e2e::alice(), &is_active).dry_run().await?;
-            assert!(!is_active_result.return_value());
+            let get_next_game_id = call_builder.get_next_game_id();
+            let next_game_id_result = client
+                .call(&ink_e2e::alice(), &get_next_game_id)
+                .dry_run()
+                .await?;
+            assert_eq!(next_game_id_result.return_value(), 0);
 
-            let get_holder = call_builder.get_holder();
-            let get_holder_result = client.call(&ink_e2e::alice(), &get_holder).dry_run().await?;
-            assert_eq!(get_holder_result.return_value(), None);
+            let get_active_game_count = call_builder.get_active_game_count();
+            let active_game_count_result = client
+                .call(&ink_e2e::alice(), &get_active_game_count)
+                .dry_run()
+                .await?;
+            assert_eq!(active_game_count_result.return_value(), 0);
 
             Ok(())
         }
 
         #[ink_e2e::test]
-        async fn start_game_emits_event(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        async fn lobby_then_start_emits_event(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
             // Given
             let mut constructor = HotpotatoRef::new(10);
             let contract = client
@@ -365,8 +7034,86 @@ mod hotpotato {
                 .expect("instantiate failed");
             let mut call_builder = contract.call_builder::<Hotpotato>();
 
+            let address_of = call_builder.address_of(ink_e2e::keypair_to_account(&ink_e2e::bob()));
+            let bob = client
+                .call(&ink_e2e::alice(), &address_of)
+                .dry_run()
+                .await?
+                .return_value();
+
+            let create_game = call_builder.create_game(GameConfig::default());
+            let game_id = client
+                .call(&ink_e2e::alice(), &create_game)
+                .submit()
+                .await
+                .expect("create_game failed")
+                .return_value()
+                .expect("create_game returned error");
+
+            let address_of =
+                call_builder.address_of(ink_e2e::keypair_to_account(&ink_e2e::charlie()));
+            let charlie = client
+                .call(&ink_e2e::alice(), &address_of)
+                .dry_run()
+                .await?
+                .return_value();
+
+            let bob_secret = Hash::from([1; 32]);
+            let join_game = call_builder.join_game(game_id, entropy_commitment(&bob_secret, bob));
+            client
+                .call(&ink_e2e::bob(), &join_game)
+                .submit()
+                .await
+                .expect("bob join_game failed");
+
+            // Then - one player is not enough
+            let close_lobby = call_builder.close_lobby(game_id, None);
+            let too_early = client
+                .call(&ink_e2e::alice(), &close_lobby)
+                .dry_run()
+                .await?;
+            assert_eq!(
+                too_early.return_value(),
+                Err(HotPotatoError::NotEnoughPlayers)
+            );
+
+            let charlie_secret = Hash::from([2; 32]);
+            let join_game =
+                call_builder.join_game(game_id, entropy_commitment(&charlie_secret, charlie));
+            client
+                .call(&ink_e2e::charlie(), &join_game)
+                .submit()
+                .await
+                .expect("charlie join_game failed");
+
+            client
+                .call(&ink_e2e::alice(), &close_lobby)
+                .submit()
+                .await
+                .expect("close_lobby failed");
+
+            // And - the game waits for every reveal
+            let start_game = call_builder.start_game(game_id);
+            let pending = client
+                .call(&ink_e2e::alice(), &start_game)
+                .dry_run()
+                .await?;
+            assert_eq!(pending.return_value(), Err(HotPotatoError::EntropyPending));
+
+            let reveal = call_builder.reveal_entropy(game_id, bob_secret);
+            client
+                .call(&ink_e2e::bob(), &reveal)
+                .submit()
+                .await
+                .expect("bob reveal_entropy failed");
+            let reveal = call_builder.reveal_entropy(game_id, charlie_secret);
+            client
+                .call(&ink_e2e::charlie(), &reveal)
+                .submit()
+                .await
+                .expect("charlie reveal_entropy failed");
+
             // When
-            let start_game = call_builder.start_game(ink_e2e::bob().account_id());
             let start_result = client
                 .call(&ink_e2e::alice(), &start_game)
                 .submit()
@@ -374,23 +7121,29 @@ mod hotpotato {
                 .expect("start_game failed");
 
             // Then - Check that GameStarted event was emitted
-            let events = start_result.events;
-            assert!(!events.is_empty(), "Expected GameStarted event to be emitted");
+            let started: Vec<GameStarted> = decode_events(start_result.contract_emitted_events()?);
+            assert_eq!(started.len(), 1);
+            assert_eq!(started[0].game_id, game_id);
 
             // Check game state
-            let is_active = call_builder.is_active();
+            let is_active = call_builder.is_active(game_id);
             let is_active_result = client.call(&ink_e2e::alice(), &is_active).dry_run().await?;
             assert!(is_active_result.return_value());
 
-            let get_holder = call_builder.get_holder();
-            let get_holder_result = client.call(&ink_e2e::alice(), &get_holder).dry_run().await?;
-            assert_eq!(get_holder_result.return_value(), Some(ink_e2e::bob().account_id()));
+            // The beacon picked one of the two players
+            let get_holder = call_builder.get_holder(game_id, 0);
+            let holder = client
+                .call(&ink_e2e::alice(), &get_holder)
+                .dry_run()
+                .await?
+                .return_value();
+            assert!(holder == Some(bob) || holder == Some(charlie));
 
             Ok(())
         }
 
         #[ink_e2e::test]
-        async fn pass_potato_emits_event(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+        async fn pass_potato_between_signers(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
             // Given
             let mut constructor = HotpotatoRef::new(10);
             let contract = client
@@ -400,30 +7153,180 @@ mod hotpotato {
                 .expect("instantiate failed");
             let mut call_builder = contract.call_builder::<Hotpotato>();
 
-            // Start game with Bob as initial holder
-            let start_game = call_builder.start_game(ink_e2e::bob().account_id());
-            let _start_result = client
+            let address_of = call_builder.address_of(ink_e2e::keypair_to_account(&ink_e2e::bob()));
+            let bob = client
+                .call(&ink_e2e::alice(), &address_of)
+                .dry_run()
+                .await?
+                .return_value();
+            let address_of =
+                call_builder.address_of(ink_e2e::keypair_to_account(&ink_e2e::charlie()));
+            let charlie = client
+                .call(&ink_e2e::alice(), &address_of)
+                .dry_run()
+                .await?
+                .return_value();
+
+            let create_game = call_builder.create_game(GameConfig::default());
+            let game_id = client
+                .call(&ink_e2e::alice(), &create_game)
+                .submit()
+                .await
+                .expect("create_game failed")
+                .return_value()
+                .expect("create_game returned error");
+
+            let bob_secret = Hash::from([1; 32]);
+            let join_game = call_builder.join_game(game_id, entropy_commitment(&bob_secret, bob));
+            client
+                .call(&ink_e2e::bob(), &join_game)
+                .submit()
+                .await
+                .expect("bob join_game failed");
+            let charlie_secret = Hash::from([2; 32]);
+            let join_game =
+                call_builder.join_game(game_id, entropy_commitment(&charlie_secret, charlie));
+            client
+                .call(&ink_e2e::charlie(), &join_game)
+                .submit()
+                .await
+                .expect("charlie join_game failed");
+
+            let close_lobby = call_builder.close_lobby(game_id, None);
+            client
+                .call(&ink_e2e::alice(), &close_lobby)
+                .submit()
+                .await
+                .expect("close_lobby failed");
+            let reveal = call_builder.reveal_entropy(game_id, bob_secret);
+            client
+                .call(&ink_e2e::bob(), &reveal)
+                .submit()
+                .await
+                .expect("bob reveal_entropy failed");
+            let reveal = call_builder.reveal_entropy(game_id, charlie_secret);
+            client
+                .call(&ink_e2e::charlie(), &reveal)
+                .submit()
+                .await
+                .expect("charlie reveal_entropy failed");
+
+            let start_game = call_builder.start_game(game_id);
+            client
                 .call(&ink_e2e::alice(), &start_game)
                 .submit()
                 .await
                 .expect("start_game failed");
 
-            // When - Bob passes potato to Charlie
-            let pass_potato = call_builder.pass_potato(ink_e2e::charlie().account_id());
+            // The beacon decides who holds first
+            let get_holder = call_builder.get_holder(game_id, 0);
+            let first_holder = client
+                .call(&ink_e2e::alice(), &get_holder)
+                .dry_run()
+                .await?
+                .return_value();
+            let (holder_signer, holder, other_signer, other) = if first_holder == Some(bob) {
+                (ink_e2e::bob(), bob, ink_e2e::charlie(), charlie)
+            } else {
+                (ink_e2e::charlie(), charlie, ink_e2e::bob(), bob)
+            };
+
+            // When - the holder passes the potato to the other player
+            let pass_potato = call_builder.pass_potato(game_id, 0, other);
             let pass_result = client
-                .call(&ink_e2e::bob(), &pass_potato)
+                .call(&holder_signer, &pass_potato)
                 .submit()
                 .await
                 .expect("pass_potato failed");
 
             // Then - Check that PotatoPassed event was emitted
-            let events = pass_result.events;
-            assert!(!events.is_empty(), "Expected PotatoPassed event to be emitted");
+            let passed: Vec<PotatoPassed> = decode_events(pass_result.contract_emitted_events()?);
+            assert_eq!(passed.len(), 1);
+            assert_eq!(passed[0].from, holder);
+            assert_eq!(passed[0].to, other);
 
-            // Check game state
-            let get_holder = call_builder.get_holder();
-            let get_holder_result = client.call(&ink_e2e::alice(), &get_holder).dry_run().await?;
-            assert_eq!(get_holder_result.return_value(), Some(ink_e2e::charlie().account_id()));
+            let get_holder_result = client
+                .call(&ink_e2e::alice(), &get_holder)
+                .dry_run()
+                .await?;
+            assert_eq!(get_holder_result.return_value(), Some(other));
+
+            // And - a different signer can pass it straight back
+            let pass_back = call_builder.pass_potato(game_id, 0, holder);
+            client
+                .call(&other_signer, &pass_back)
+                .submit()
+                .await
+                .expect("pass back failed");
+
+            let get_holder_result = client
+                .call(&ink_e2e::alice(), &get_holder)
+                .dry_run()
+                .await?;
+            assert_eq!(get_holder_result.return_value(), Some(holder));
+
+            // But - nobody can pass to an account that never joined
+            let address_of = call_builder.address_of(ink_e2e::keypair_to_account(&ink_e2e::dave()));
+            let dave = client
+                .call(&ink_e2e::alice(), &address_of)
+                .dry_run()
+                .await?
+                .return_value();
+            let pass_outside = call_builder.pass_potato(game_id, 0, dave);
+            let pass_outside_result = client.call(&holder_signer, &pass_outside).dry_run().await?;
+            assert_eq!(
+                pass_outside_result.return_value(),
+                Err(HotPotatoError::RecipientNotRegistered)
+            );
+
+            // And - the other player (no longer the holder) cannot pass
+            let pass_potato = call_builder.pass_potato(game_id, 0, other);
+            let wrong_holder_result = client.call(&other_signer, &pass_potato).dry_run().await?;
+            assert_eq!(
+                wrong_holder_result.return_value(),
+                Err(HotPotatoError::NotCurrentHolder)
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn concurrent_games_work(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            // Given
+            let mut constructor = HotpotatoRef::new(10);
+            let contract = client
+                .instantiate("hotpotato", &ink_e2e::alice(), &mut constructor)
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut call_builder = contract.call_builder::<Hotpotato>();
+
+            // When - Alice and Charlie each open a table
+            let create_game = call_builder.create_game(GameConfig::default());
+            let first = client
+                .call(&ink_e2e::alice(), &create_game)
+                .submit()
+                .await
+                .expect("first create_game failed")
+                .return_value()
+                .expect("first create_game returned error");
+            let second = client
+                .call(&ink_e2e::charlie(), &create_game)
+                .submit()
+                .await
+                .expect("second create_game failed")
+                .return_value()
+                .expect("second create_game returned error");
+
+            // Then
+            assert_ne!(first, second);
+
+            let get_active_games = call_builder.get_active_games(0, 10);
+            let active_games_result = client
+                .call(&ink_e2e::alice(), &get_active_games)
+                .dry_run()
+                .await?;
+            assert_eq!(active_games_result.return_value(), vec![first, second]);
 
             Ok(())
         }